@@ -0,0 +1,114 @@
+/* 
+Copyright (c) 2024  NickelAnge.Studio 
+Email               mathieu.grenier@nickelange.studio
+Git                 https://github.com/NickelAngeStudio/nsrb
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFcircularEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Push and pop throughput of checked and unchecked ring buffers of several sizes. Checked sizes come in pairs of a power
+//! of 2 and the size right below it, since a power of 2 wraps its cursors with a mask instead of a comparison.
+//! 
+//! Run with `cargo bench`.
+
+#[macro_use] extern crate nsrb;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+/// Maximum count of element pushed per iteration.
+const ELEMENTS : usize = 4096;
+
+ring!(Checked15[u32; 15]);
+ring!(Checked16[u32; 16]);
+ring!(Checked255[u32; 255]);
+ring!(Checked256[u32; 256]);
+ring!(Checked4095[u32; 4095]);
+ring!(Checked4096[u32; 4096]);
+ring!(@unchecked(u8) UncheckedU8[u32]);
+ring!(@unchecked(u16) UncheckedU16[u32]);
+
+/// Push and pop as many full bursts as fit in `ELEMENTS`. Throughput is the count of elements actually pushed, which is
+/// lower than `ELEMENTS` when the capacity doesn't divide it, like the 15 slots of a 16 sized checked buffer.
+macro_rules! bench_ring {
+    ($group : ident, $name : ident) => {
+        let burst = $name::capacity().min(ELEMENTS);
+        let pushed = ELEMENTS / burst * burst;
+        $group.throughput(Throughput::Elements(pushed as u64));
+        $group.bench_function(BenchmarkId::new(stringify!($name), $name::capacity()), |b| {
+            let mut rb = $name::new();
+            b.iter(|| {
+                let mut sum = 0u32;
+                for start in (0..pushed).step_by(burst) {
+                    for i in start..start + burst {
+                        rb.push(black_box(i as u32));
+                    }
+                    while let Some(&item) = rb.pop() {
+                        sum = sum.wrapping_add(item);
+                    }
+                }
+                black_box(sum)
+            });
+        });
+    };
+}
+
+/// Push `ELEMENTS` elements into a full buffer, overwriting the oldest each time.
+macro_rules! bench_overwrite {
+    ($group : ident, $name : ident) => {
+        $group.bench_function(BenchmarkId::new(stringify!($name), $name::capacity()), |b| {
+            let mut rb = $name::new();
+            rb.extend(0..$name::capacity() as u32);
+            b.iter(|| {
+                for i in 0..ELEMENTS {
+                    rb.push(black_box(i as u32));
+                }
+            });
+        });
+    };
+}
+
+fn push_pop(c : &mut Criterion) {
+    let mut group = c.benchmark_group("push_pop");
+    bench_ring!(group, Checked15);
+    bench_ring!(group, Checked16);
+    bench_ring!(group, Checked255);
+    bench_ring!(group, Checked256);
+    bench_ring!(group, Checked4095);
+    bench_ring!(group, Checked4096);
+    bench_ring!(group, UncheckedU8);
+    bench_ring!(group, UncheckedU16);
+    group.finish();
+}
+
+fn overwrite(c : &mut Criterion) {
+    let mut group = c.benchmark_group("overwrite");
+    group.throughput(Throughput::Elements(ELEMENTS as u64));
+    bench_overwrite!(group, Checked15);
+    bench_overwrite!(group, Checked16);
+    bench_overwrite!(group, Checked255);
+    bench_overwrite!(group, Checked256);
+    bench_overwrite!(group, Checked4095);
+    bench_overwrite!(group, Checked4096);
+    bench_overwrite!(group, UncheckedU8);
+    bench_overwrite!(group, UncheckedU16);
+    group.finish();
+}
+
+criterion_group!(benches, push_pop, overwrite);
+criterion_main!(benches);
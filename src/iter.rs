@@ -0,0 +1,181 @@
+/* 
+Copyright (c) 2024  NickelAnge.Studio 
+Email               mathieu.grenier@nickelange.studio
+Git                 https://github.com/NickelAngeStudio/nsrb
+
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFcircularEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+use core::slice;
+
+/// Iterator over the elements of a ring buffer in FIFO order, returned by `iter()`.
+/// 
+/// Elements wrapping at the end of the backing array are held as 2 slices. Skipping elements with `nth()` or `skip()` is O(1).
+#[derive(Clone, Debug)]
+pub struct Iter<'a, T> {
+    front : slice::Iter<'a, T>,
+    back : slice::Iter<'a, T>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    #[doc(hidden)]
+    #[inline(always)]
+    pub fn new(front : &'a [T], back : &'a [T]) -> Iter<'a, T> {
+        Iter { front : front.iter(), back : back.iter() }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<&'a T> {
+        match self.front.next() {
+            Some(item) => Some(item),
+            None => self.back.next(),
+        }
+    }
+
+    #[inline(always)]
+    fn nth(&mut self, n : usize) -> Option<&'a T> {
+        let front = self.front.len();
+        if n < front {
+            self.front.nth(n)
+        } else {
+            self.front.nth(front);
+            self.back.nth(n - front)
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.front.len() + self.back.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<&'a T> {
+        match self.back.next_back() {
+            Some(item) => Some(item),
+            None => self.front.next_back(),
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+/// Mutable iterator over the elements of a ring buffer in FIFO order, returned by `iter_mut()`.
+#[derive(Debug)]
+pub struct IterMut<'a, T> {
+    front : slice::IterMut<'a, T>,
+    back : slice::IterMut<'a, T>,
+}
+
+impl<'a, T> IterMut<'a, T> {
+    #[doc(hidden)]
+    #[inline(always)]
+    pub fn new(front : &'a mut [T], back : &'a mut [T]) -> IterMut<'a, T> {
+        IterMut { front : front.iter_mut(), back : back.iter_mut() }
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<&'a mut T> {
+        match self.front.next() {
+            Some(item) => Some(item),
+            None => self.back.next(),
+        }
+    }
+
+    #[inline(always)]
+    fn nth(&mut self, n : usize) -> Option<&'a mut T> {
+        let front = self.front.len();
+        if n < front {
+            self.front.nth(n)
+        } else {
+            self.front.nth(front);
+            self.back.nth(n - front)
+        }
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.front.len() + self.back.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        match self.back.next_back() {
+            Some(item) => Some(item),
+            None => self.front.next_back(),
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+/// Iterator returning copies of the elements removed from a ring buffer in FIFO order, returned by `drain()`.
+/// 
+/// The ring buffer is emptied when `drain()` is called, even if the iterator isn't consumed.
+#[derive(Clone, Debug)]
+pub struct Drain<'a, T>(Iter<'a, T>);
+
+impl<'a, T> Drain<'a, T> {
+    #[doc(hidden)]
+    #[inline(always)]
+    pub fn new(front : &'a [T], back : &'a [T]) -> Drain<'a, T> {
+        Drain(Iter::new(front, back))
+    }
+}
+
+impl<T : Copy> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    #[inline(always)]
+    fn next(&mut self) -> Option<T> {
+        self.0.next().copied()
+    }
+
+    #[inline(always)]
+    fn nth(&mut self, n : usize) -> Option<T> {
+        self.0.nth(n).copied()
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<T : Copy> DoubleEndedIterator for Drain<'_, T> {
+    #[inline(always)]
+    fn next_back(&mut self) -> Option<T> {
+        self.0.next_back().copied()
+    }
+}
+
+impl<T : Copy> ExactSizeIterator for Drain<'_, T> {}
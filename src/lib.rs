@@ -67,6 +67,129 @@ pub const NSRB_LOWER_LIMIT : usize = 2;
 /// Can be removed via the `no_limit` feature.
 pub const NSRB_UPPER_LIMIT : usize = u16::MAX as usize;
 
+/// Largest count of bytes the backing array of an unchecked buffer can occupy. Default : 1 MiB.
+/// 
+/// A [u16] unchecked buffer holds 65536 elements thus large elements would overflow the stack. Checked at compile time.
+/// Can be removed via the `no_limit` feature.
+pub const NSRB_STACK_LIMIT : usize = 1 << 20;
+
+/// Returns the size of a ring buffer holding `n` elements, since 1 slot is reserved to tell a full buffer from an empty one.
+/// 
+/// `ring!(Rx[u8; nsrb::capacity_for_bytes(1024)])` has a `capacity()` of exactly 1024 bytes.
+pub const fn capacity_for_bytes(n : usize) -> usize {
+    n + 1
+}
+
+/// Error returned by `try_new()` when the buffer size is out of limits, or by `with_capacity_checked()`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RingError {
+    /// Size is smaller than [NSRB_LOWER_LIMIT].
+    TooSmall,
+
+    /// Size is larger than [NSRB_UPPER_LIMIT].
+    TooLarge,
+
+    /// Requested capacity is larger than the capacity of the buffer.
+    CapacityExceeded,
+}
+
+impl core::fmt::Display for RingError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RingError::TooSmall => write!(f, "Buffer size is smaller than {}.", NSRB_LOWER_LIMIT),
+            RingError::TooLarge => write!(f, "Buffer size is larger than {}.", NSRB_UPPER_LIMIT),
+            RingError::CapacityExceeded => write!(f, "Requested capacity is larger than the buffer capacity."),
+        }
+    }
+}
+
+/// Length of a ring buffer, implemented by each buffer created with [`ring!`].
+/// 
+/// Import it with `use nsrb::RingLen;` to call `len()` and `is_empty()`. A trait rather than inherent methods so an `impl`
+/// block can still define its own `len()` or `is_empty()`, like the `Extra` example of [`ring!`], which takes precedence.
+pub trait RingLen {
+    /// Returns the count of element in the buffer.
+    fn len(&self) -> usize;
+
+    /// Returns true if the buffer contains no element.
+    #[inline(always)]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Raw cursors of a checked ring buffer created with [`ring!`], read without accessing fields.
+/// 
+/// Import it with `use nsrb::RingCursors;` to call `head()` and `tail()`. A trait rather than inherent methods so an
+/// `impl` block can still define its own `head()` or `tail()`, like the crate example, which takes precedence.
+pub trait RingCursors {
+    /// Returns the index in the backing array where the next element will be pushed.
+    fn head(&self) -> usize;
+
+    /// Returns the index in the backing array of the oldest element.
+    fn tail(&self) -> usize;
+}
+
+/// Opaque read position in a broadcast ring buffer, returned by `cursor()` and `read_from()`.
+/// 
+/// A cursor counts pushed elements thus it stays valid when the buffer wraps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cursor(usize);
+
+impl Cursor {
+    #[doc(hidden)]
+    #[inline(always)]
+    pub const fn from_sequence(sequence : usize) -> Cursor {
+        Cursor(sequence)
+    }
+
+    #[doc(hidden)]
+    #[inline(always)]
+    pub const fn sequence(&self) -> usize {
+        self.0
+    }
+}
+
+/// Error returned by `read_from()` when elements were overwritten or popped before being read.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Lagged {
+    /// Count of element that were lost.
+    pub missed : usize,
+
+    /// Cursor positioned on the oldest element still in the buffer.
+    pub cursor : Cursor,
+}
+
+/// Display the bytes of a [u8] ring buffer in FIFO order as hexadecimal without allocating, returned by `hex()`.
+/// 
+/// A [u8] ring buffer containing `[0xDE, 0xAD, 0xBE, 0xEF]` is displayed as `DE AD BE EF`.
+#[derive(Clone, Copy, Debug)]
+pub struct HexDisplay<'a>(pub &'a [u8], pub &'a [u8]);
+
+impl core::fmt::Display for HexDisplay<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (i, item) in self.0.iter().chain(self.1.iter()).enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{:02X}", item)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+#[doc(hidden)]
+pub use arbitrary;
+
+#[cfg(feature = "zeroize")]
+#[doc(hidden)]
+pub use zeroize;
+
+mod iter;
+pub use iter::{Iter, IterMut, Drain};
+
 #[doc(hidden)]
 pub mod ring;
 
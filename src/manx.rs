@@ -29,7 +29,7 @@ SOFTWARE.
 /// ## Checked
 /// 
 /// Checked manx buffer need a size specified and execute extra instructions to prevent [integer overflow](https://doc.rust-lang.org/beta/book/ch03-02-data-types.html#integer-overflow).
-
+///
 /// ##### `$(#[$attr:meta])*`
 /// Extra [attributes](https://doc.rust-lang.org/reference/attributes.html) for the ring buffer. *`Optional`*
 /// 
@@ -44,6 +44,13 @@ SOFTWARE.
 /// 
 /// ##### `$size`
 /// Count of element in the buffer. Limit is between [NSRB_LOWER_LIMIT](super::NSRB_LOWER_LIMIT) and [NSRB_UPPER_LIMIT](super::NSRB_UPPER_LIMIT) unless the `no_limit` feature is specified.
+/// A size of 0 is always rejected at compile time, even with the `no_limit` feature.
+/// The `no_limit` feature is read from nsrb rather than from the crate calling the macro, thus any crate of the dependency
+/// graph enabling it removes the limits of every buffer.
+/// ```compile_fail
+/// #[macro_use] extern crate nsrb;
+/// nsrb::manx!(Empty[u8; 0]);
+/// ```
 /// 
 /// #### Example
 /// ```
@@ -81,7 +88,8 @@ SOFTWARE.
 /// ##### `$name`
 /// Name of the circular buffer struct without `"`.
 /// ##### `$type`
-/// Type contained in the buffer. Must implement [Copy] and [Default] traits.
+/// Type contained in the buffer. Must implement [Copy] and [Default] traits. The backing array can't be larger than
+/// [NSRB_STACK_LIMIT](super::NSRB_STACK_LIMIT) bytes unless the `no_limit` feature is specified.
 /// 
 /// #### Example
 /// ```
@@ -109,14 +117,40 @@ SOFTWARE.
 /// ## Implementation
 /// Each manx buffer provides those method by default.
 /// 
+/// #### `$name::SIZE`
+/// Count of element in the buffer, which is `$size` for checked and `$int::MAX + 1` for unchecked.
+/// 
 /// #### `$name::new()`
-/// Create a new instance of `$name` fixed manx buffer.
+/// Create a new instance of `$name` fixed manx buffer. Panics if `$size` is out of limits.
+/// 
+/// #### `$name::try_new() -> Result<$name, RingError>`
+/// Create a new instance of `$name` fixed manx buffer or returns a [RingError](super::RingError) if `$size` is out of limits.
 /// 
 /// #### `$name::push(item : $type)`
 /// Push an item into `$name` manx buffer.
 /// 
 /// #### `$name::items() -> &$type[]`
 /// Returns a read only reference to the buffer.
+/// 
+/// #### `$name::wrapped() -> bool`
+/// Returns true once `head` wrapped to the start of the buffer, meaning every slot was pushed at least once.
+/// Before that, slots after `head` still contain their default value.
+/// 
+/// #### `$name::newest() -> &$type`
+/// Returns the most recently pushed element.
+/// 
+/// #### `$name::oldest() -> &$type`
+/// Returns the oldest element, which is the next one to be overwritten. Default value until `wrapped()`.
+/// 
+/// #### `$name::average_as::<A>() -> Option<A>`
+/// Returns the average of the pushed elements, skipping default slots never pushed until `wrapped()`, to smooth sensor
+/// readings. Elements are summed as `A`, which must implement `From<$type>`, [Add](core::ops::Add), [Div](core::ops::Div)
+/// and `TryFrom<usize>`, so a wider type like `u32` for a `u8` manx prevents the sum from overflowing. Integer averages
+/// are truncated. Returns None if nothing was pushed or if the count of elements doesn't fit in `A`.
+/// 
+/// #### `$name::valid_ordered(out : &mut [$type]) -> usize`
+/// Copy the pushed elements from oldest to newest at the start of `out`, skipping default slots never pushed, and
+/// returns the count copied. That's `head` elements until `wrapped()`, then `SIZE`, truncated to `out.len()`.
 #[macro_export]
 macro_rules! manx {
     ($(#[$attr:meta])* $visibility : vis $name : ident[$type : ty; $size : expr]) => {
@@ -124,22 +158,21 @@ macro_rules! manx {
             #[$attr]
         )*
         #[allow(dead_code)]
-        $visibility struct $name { head : usize, buffer : [$type; $size], }
+        $visibility struct $name { head : usize, wrapped : bool, buffer : [$type; $size], }
+
+        // Head arithmetic needs at least 1 element, even with the `no_limit` feature.
+        const _ : () = assert!($size as usize >= 1, "Manx buffer size must be at least 1.");
 
         #[allow(dead_code)]
         impl $name {
-            pub fn new() -> $name {             
-            
-                #[cfg(not(feature = "no_limit"))]
-                assert!($size as usize >= $crate::NSRB_LOWER_LIMIT);
-
-                #[cfg(not(feature = "no_limit"))]
-                assert!($size as usize <= $crate::NSRB_UPPER_LIMIT);
+            pub fn try_new() -> Result<$name, $crate::RingError> {
+                $crate::ring_size_limits!($size);
 
-                $name {
+                Ok($name {
                     head: 0,
+                    wrapped: false,
                     buffer: [<$type>::default(); $size],
-                }
+                })
             }
 
             #[inline(always)]
@@ -147,6 +180,7 @@ macro_rules! manx {
                 self.buffer[self.head] = item;
                 if self.head >= $size - 1 {
                     self.head = 0;
+                    self.wrapped = true;
                 } else {
                     self.head += 1;
                 }
@@ -156,9 +190,9 @@ macro_rules! manx {
             pub fn items(&self) -> &[$type; $size] {
                 &self.buffer
             }
+        }
 
-           
-        }   
+        $crate::manx_core!($name[$type; $size]);
     };
     (@unchecked($int:ty) $(#[$attr:meta])* $visibility : vis $name : ident[$type : ty]) => {
         $(
@@ -167,57 +201,136 @@ macro_rules! manx {
         #[allow(dead_code)]
         $visibility struct $name {
             head : $int,
+            wrapped : bool,
             buffer : [$type; <$int>::MAX as usize + 1],
         }
 
+        $crate::ring_stack_limit!($int, $type);
+
         #[allow(dead_code)]
         impl $name {
-            pub fn new() -> $name {
-
-                #[cfg(not(feature = "no_limit"))]
-                assert!(<$int>::MAX as usize <= $crate::NSRB_UPPER_LIMIT);
+            pub fn try_new() -> Result<$name, $crate::RingError> {
+                $crate::ring_size_limits!(<$int>::MAX as usize);
 
-                $name {
+                Ok($name {
                     head: 0,
+                    wrapped: false,
                     buffer: [<$type>::default(); <$int>::MAX as usize + 1],
-                }
+                })
             }
 
             #[inline(always)]
             pub fn push(&mut self, item : $type) {
                 self.buffer[self.head as usize] = item;
-                self.head += 1;
+                self.head = self.head.wrapping_add(1);
+                if self.head == 0 {
+                    self.wrapped = true;
+                }
             }
 
             #[inline(always)]
             pub fn items(&self) -> &[$type; <$int>::MAX as usize + 1] {
                 &self.buffer
             }
-        }   
+        }
+
+        $crate::manx_core!($name[$type; <$int>::MAX as usize + 1]);
     };
 
 }
 
+/// Implementation shared by every manx buffer arm.
+/// 
+/// Arms must declare `head`, `wrapped` and `buffer` fields and implement their own `try_new` and `push`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! manx_core {
+    ($name : ident[$type : ty; $size : expr]) => {
+        #[allow(dead_code)]
+        impl $name {
+            pub const SIZE : usize = $size;
+
+            pub fn new() -> $name {
+                Self::try_new().unwrap()
+            }
+
+            #[inline(always)]
+            pub fn wrapped(&self) -> bool {
+                self.wrapped
+            }
+
+            #[inline(always)]
+            pub fn newest(&self) -> &$type {
+                match self.head as usize {
+                    0 => &self.buffer[self.buffer.len() - 1],
+                    head => &self.buffer[head - 1],
+                }
+            }
+
+            #[inline(always)]
+            pub fn oldest(&self) -> &$type {
+                &self.buffer[self.head as usize]
+            }
+
+            pub fn average_as<A>(&self) -> Option<A>
+                where A : From<$type> + ::core::ops::Add<Output = A> + ::core::ops::Div<Output = A> + TryFrom<usize> {
+                let count = if self.wrapped { self.buffer.len() } else { self.head as usize };
+                let (first, rest) = self.buffer[..count].split_first()?;
+
+                let sum = rest.iter().fold(A::from(*first), |sum, &item| sum + A::from(item));
+                Some(sum / A::try_from(count).ok()?)
+            }
+
+            pub fn valid_ordered(&self, out : &mut [$type]) -> usize {
+                let head = self.head as usize;
+                let (older, newer) = if self.wrapped {
+                    (&self.buffer[head..], &self.buffer[..head])
+                } else {
+                    (&[][..], &self.buffer[..head])
+                };
+
+                let mut count = 0;
+                for (slot, &item) in out.iter_mut().zip(older.iter().chain(newer.iter())) {
+                    *slot = item;
+                    count += 1;
+                }
+                count
+            }
+        }
+    };
+}
+
 #[cfg(test)]
-#[cfg(not(feature = "no_limit"))]   // Only limit features are tested
 pub(crate) mod tests_checked {
 
     // Test the lower limit of ring buffer
+    #[cfg(not(feature = "no_limit"))]
     manx!(TooSmall[usize;super::super::NSRB_LOWER_LIMIT - 1]);
     #[test]
+    #[cfg(not(feature = "no_limit"))]
     #[should_panic]
     fn manx_lower_limit() {
         let _ = TooSmall::new();
     }
 
     // Test the upper limit of ring buffer
+    #[cfg(not(feature = "no_limit"))]
     manx!(TooBig[usize;super::super::NSRB_UPPER_LIMIT + 1]);
     #[test]
+    #[cfg(not(feature = "no_limit"))]
     #[should_panic]
     fn manx_upper_limit() {
         let _ = TooBig::new();
     }
 
+    // Test limits without panicking
+    #[test]
+    #[cfg(not(feature = "no_limit"))]
+    fn manx_try_new() {
+        assert_eq!(TooSmall::try_new().err(), Some(crate::RingError::TooSmall));
+        assert_eq!(TooBig::try_new().err(), Some(crate::RingError::TooLarge));
+    }
+
     // Test push and items
     manx!(ManxPush[usize;10]);
     #[test]
@@ -240,21 +353,137 @@ pub(crate) mod tests_checked {
 
     }
 
+    // Test wrapped flag
+    manx!(ManxWrap[usize;10]);
+    #[test]
+    fn manx_wrapped() {
+        let mut rb = ManxWrap::new();
+
+        for i in 0..9 {
+            rb.push(i);
+            assert!(!rb.wrapped());
+        }
+
+        rb.push(9);
+        assert!(rb.wrapped());
+
+        rb.push(10);
+        assert!(rb.wrapped());
+    }
+
+    // Test size in const context
+    const MANX_SIZE_ARRAY : [usize; ManxWrap::SIZE] = [0; ManxWrap::SIZE];
+    #[test]
+    fn manx_size() {
+        assert_eq!(MANX_SIZE_ARRAY.len(), 10);
+        assert_eq!(ManxWrap::new().items().len(), ManxWrap::SIZE);
+    }
+
+    // Test chronological copy of pushed elements
+    manx!(ManxOrdered[usize;4]);
+    #[test]
+    fn manx_valid_ordered() {
+        let mut rb = ManxOrdered::new();
+        let mut out = [0; 6];
+        assert_eq!(rb.valid_ordered(&mut out), 0);
+
+        rb.push(1);
+        rb.push(2);
+        rb.push(3);
+        assert_eq!(rb.valid_ordered(&mut out), 3);
+        assert_eq!(out[..3], [1, 2, 3]);
+
+        rb.push(4);
+        rb.push(5);
+        rb.push(6);
+        assert_eq!(rb.valid_ordered(&mut out), 4);
+        assert_eq!(out[..4], [3, 4, 5, 6]);
+
+        let mut short = [0; 2];
+        assert_eq!(rb.valid_ordered(&mut short), 2);
+        assert_eq!(short, [3, 4]);
+    }
+
+    // Test average of the window
+    manx!(ManxAverage[i32;4]);
+    #[test]
+    fn manx_average() {
+        let mut rb = ManxAverage::new();
+        assert_eq!(rb.average_as::<i32>(), None);
+
+        rb.push(20);
+        rb.push(23);
+        assert_eq!(rb.average_as::<i32>(), Some(21));   // Default slots aren't averaged
+
+        for reading in [-4, 10, 30, 40] {
+            rb.push(reading);
+        }
+        assert_eq!(rb.average_as::<i64>(), Some(19));
+    }
+
+    // Test average of elements which sum overflows the element type
+    manx!(ManxAverageWide[u8;7]);
+    #[test]
+    fn manx_average_overflow() {
+        let mut rb = ManxAverageWide::new();
+        for reading in [200, 250, 210, 240, 220, 230, 255] {
+            rb.push(reading);
+        }
+        assert_eq!(rb.average_as::<u32>(), Some(229));
+        assert_eq!(rb.average_as::<u16>(), Some(229));
+    }
+
+    // Test newest and oldest accessors
+    manx!(ManxEnds[usize;4]);
+    #[test]
+    fn manx_newest_oldest() {
+        let mut rb = ManxEnds::new();
+
+        rb.push(1);
+        rb.push(2);
+        assert_eq!(*rb.newest(), 2);
+        assert_eq!(*rb.oldest(), 0);
+
+        rb.push(3);
+        rb.push(4);
+        assert_eq!(*rb.newest(), 4);
+        assert_eq!(*rb.oldest(), 1);
+
+        rb.push(5);
+        assert_eq!(*rb.newest(), 5);
+        assert_eq!(*rb.oldest(), 2);
+    }
+
 }
 
 
 #[cfg(test)]
-#[cfg(not(feature = "no_limit"))]   // Only limit features are tested
 pub(crate) mod tests_unchecked {
 
     // Test the upper limit of ring buffer
+    #[cfg(not(feature = "no_limit"))]
     manx!(@unchecked(u32) TooBig[usize]);
     #[test]
+    #[cfg(not(feature = "no_limit"))]
     #[should_panic]
     fn manx_upper_limit() {
         let _ = TooBig::new();
     }
 
+    // Test average of a wrapped buffer larger than u16::MAX
+    manx!(@unchecked(u16) ManxAverage[u32]);
+    #[test]
+    fn manx_average() {
+        let mut rb = ManxAverage::new();
+        for reading in 0..70_000 {
+            rb.push(reading % 100);
+        }
+        assert!(rb.wrapped());
+
+        let sum : u32 = (70_000 - 65_536..70_000).map(|reading| reading % 100).sum();
+        assert_eq!(rb.average_as::<u32>(), Some(sum / 65_536));
+    }
+
     // Test push and items
     manx!(@unchecked(u8) ManxPush[usize]);
     #[test]
@@ -293,4 +522,19 @@ pub(crate) mod tests_unchecked {
 
     }
 
+    // Test wrapped flag
+    manx!(@unchecked(u8) ManxWrap[usize]);
+    #[test]
+    fn manx_wrapped() {
+        let mut rb = ManxWrap::new();
+
+        for i in 0..u8::MAX as usize {
+            rb.push(i);
+            assert!(!rb.wrapped());
+        }
+
+        rb.push(0);
+        assert!(rb.wrapped());
+    }
+
 }
\ No newline at end of file
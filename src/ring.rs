@@ -28,7 +28,7 @@ SOFTWARE.
 /// ## Checked
 /// 
 /// Checked ring buffer need a size specified and execute extra instructions to prevent [integer overflow](https://doc.rust-lang.org/beta/book/ch03-02-data-types.html#integer-overflow).
-
+///
 /// ##### `$(#[$attr:meta])*`
 /// Extra [attributes](https://doc.rust-lang.org/reference/attributes.html) for the ring buffer. *`Optional`*
 /// 
@@ -43,6 +43,14 @@ SOFTWARE.
 /// 
 /// ##### `$size`
 /// Count of element in the buffer. Limit is between [NSRB_LOWER_LIMIT](super::NSRB_LOWER_LIMIT) and [NSRB_UPPER_LIMIT](super::NSRB_UPPER_LIMIT) unless the `no_limit` feature is specified.
+/// The buffer lives on the stack thus its size is fixed at declaration and can't grow nor shrink afterward.
+/// A size of 0 is always rejected at compile time, even with the `no_limit` feature.
+/// The `no_limit` feature is read from nsrb rather than from the crate calling the macro, thus any crate of the dependency
+/// graph enabling it removes the limits of every buffer.
+/// ```compile_fail
+/// #[macro_use] extern crate nsrb;
+/// nsrb::ring!(Empty[u8; 0]);
+/// ```
 /// 
 /// #### Example
 /// ```
@@ -66,8 +74,32 @@ SOFTWARE.
 /// }
 /// 
 /// ```
+/// ## Unlimited
+/// Unlimited ring buffer is a checked ring buffer ignoring [NSRB_LOWER_LIMIT](super::NSRB_LOWER_LIMIT) and
+/// [NSRB_UPPER_LIMIT](super::NSRB_UPPER_LIMIT), so a single buffer can opt out while others keep the limits, unlike the
+/// `no_limit` feature. A size of 0 is still rejected at compile time.
+/// 
+/// ##### `$(#[$attr:meta])*`, `$visibility`, `$name`, `$type`, `$size`
+/// Same as checked ring buffer.
+/// 
+/// #### Example
+/// ```
+/// #[macro_use] extern crate nsrb;
+/// use nsrb::RingLen;
+/// 
+/// nsrb::ring!(@unlimited Samples[u8; 100_000]);
+/// 
+/// fn main() {
+///     let samples = Samples::new();
+///     assert!(samples.is_empty());
+///     assert_eq!(Samples::capacity(), 99_999);
+/// }
+/// ```
+/// 
 /// ## Unchecked
 /// Unchecked ring buffer use [integer overflow](https://doc.rust-lang.org/beta/book/ch03-02-data-types.html#integer-overflow) to wrap head and tail thus need less intructions.
+/// Wrapping is explicit so it doesn't panic when overflow checks are enabled. Like checked ring buffer, one slot is reserved
+/// to tell a full buffer from an empty one, thus it holds up to `$int::MAX` elements.
 /// 
 /// 
 /// ##### `$int`
@@ -80,7 +112,13 @@ SOFTWARE.
 /// ##### `$name`
 /// Name of the circular buffer struct without `"`.
 /// ##### `$type`
-/// Type contained in the buffer. Must implement [Copy] and [Default] traits.
+/// Type contained in the buffer. Must implement [Copy] and [Default] traits. The backing array can't be larger than
+/// [NSRB_STACK_LIMIT](super::NSRB_STACK_LIMIT) bytes unless the `no_limit` feature is specified.
+#[cfg_attr(not(feature = "no_limit"), doc = "```compile_fail")]
+#[cfg_attr(feature = "no_limit", doc = "```ignore")]
+/// #[macro_use] extern crate nsrb;
+/// nsrb::ring!(@unchecked(u16) Huge[[u64; 32]]);
+/// ```
 /// 
 /// #### Example
 /// ```
@@ -105,11 +143,216 @@ SOFTWARE.
 /// 
 /// ```
 /// 
+/// #### `$name::push_checked(item : $type)`
+/// Same as `push` but head and tail are compared against `$int::MAX` instead of relying on wrapping arithmetic, so each
+/// call can pick the safe path, like during initialization, while hot loops keep the fast `push`. Both give the same result.
+/// 
+/// ## Broadcast
+/// Broadcast ring buffer is a checked ring buffer where multiple readers consume the same elements at their own
+/// pace. `push` never rejects and a reader that fell behind loses the overwritten elements.
+/// 
+/// ##### `$readers`
+/// Maximum count of readers that can be subscribed at once.
+/// ##### `$(#[$attr:meta])*`, `$visibility`, `$name`, `$type`, `$size`
+/// Same as checked ring buffer.
+/// 
+/// #### Example
+/// ```
+/// #[macro_use] extern crate nsrb;
+/// 
+/// nsrb::ring!(@broadcast(2) Events[u32; 16]);
+/// 
+/// fn main() {
+///     let mut events = Events::new();
+///     let audio = events.subscribe().unwrap();
+///     let video = events.subscribe().unwrap();
+/// 
+///     events.push(42);
+///     assert_eq!(events.receive(audio), Ok(Some(&42)));
+///     assert_eq!(events.receive(video), Ok(Some(&42)));
+///     assert_eq!(events.receive(video), Ok(None));
+/// }
+/// ```
+/// 
+/// #### `$name::subscribe() -> Option<usize>`
+/// Register a reader that will receive elements pushed from now on. Returns None if `$readers` are already subscribed.
+/// 
+/// #### `$name::unsubscribe(reader : usize)`
+/// Release `reader` so its slot can be subscribed again.
+/// 
+/// #### `$name::receive(reader : usize) -> Result<Option<&$type>, Lagged>`
+/// Returns the next element for `reader` or None if it's up to date. Returns [Lagged](super::Lagged) with the count of
+/// lost elements if `reader` fell behind, in which case the next call resumes from the oldest element.
+/// Popped elements are lost for readers that didn't receive them yet, the same way as overwritten elements.
+/// 
+/// #### `$name::cursor() -> Cursor`
+/// Returns a [Cursor](super::Cursor) positioned on the oldest element, used with `read_from` to read without popping.
+/// 
+/// #### `$name::read_from(cursor : Cursor) -> Result<(&[$type], &[$type], Cursor), Lagged>`
+/// Returns the elements pushed since `cursor` as 2 slices in FIFO order and the cursor to resume from. Each reader can keep
+/// its own cursor without modifying the tail. Returns [Lagged](super::Lagged) if elements were overwritten or popped before
+/// being read.
+/// 
+/// Cursors count pushed elements thus only the oldest elements can be removed, with `pop`, `split_first`, `shrink_len_to_newest`
+/// or similar methods. `split_last`, `pop_back`, `clone_from_ring`, `dedup_by`, `split_off`, `swap_remove`, `remove` and
+/// `insert` aren't available on broadcast ring buffer since readers would skip or read again elements.
+/// ```compile_fail
+/// #[macro_use] extern crate nsrb;
+/// 
+/// nsrb::ring!(@broadcast(1) Events[u32; 16]);
+/// 
+/// fn main() {
+///     let mut events = Events::new();
+///     events.push(42);
+///     events.pop_back();
+/// }
+/// ```
+/// 
+/// ## Volatile
+/// Volatile ring buffer is a checked ring buffer where `push` and `pop` access elements with [write_volatile](core::ptr::write_volatile)
+/// and [read_volatile](core::ptr::read_volatile) so the compiler doesn't elide accesses to memory shared with hardware like a DMA engine.
+/// `pop` returns a copy `Option<$type>` since a reference would bypass the volatile read. Cursors and other methods aren't volatile.
+/// 
+/// ##### `$(#[$attr:meta])*`, `$visibility`, `$name`, `$type`, `$size`
+/// Same as checked ring buffer.
+/// 
+/// #### Example
+/// ```
+/// #[macro_use] extern crate nsrb;
+/// 
+/// nsrb::ring!(@volatile pub DmaRx[u8; 64]);
+/// 
+/// fn main() {
+///     let mut rx = DmaRx::new();
+///     rx.push(0xAA);
+///     assert_eq!(rx.pop(), Some(0xAA));
+/// }
+/// ```
+/// 
+/// ## Aligned
+/// Aligned ring buffer is a checked ring buffer whose backing array starts on a `$align` bytes boundary, for SIMD loads
+/// or DMA engines requiring aligned addresses. The struct size is rounded up to a multiple of `$align`.
+/// 
+/// ##### `$align`
+/// Alignment in bytes as a literal power of 2, like a 64 bytes cache line.
+/// ##### `$(#[$attr:meta])*`, `$visibility`, `$name`, `$type`, `$size`
+/// Same as checked ring buffer.
+/// 
+/// #### Example
+/// ```
+/// #[macro_use] extern crate nsrb;
+/// 
+/// nsrb::ring!(@align(64) DmaTx[u8; 256]);
+/// 
+/// fn main() {
+///     let tx = DmaTx::new();
+///     assert_eq!(tx.as_ptr() as usize % 64, 0);
+/// }
+/// ```
+/// 
+/// ## Scrub
+/// Scrub ring buffer is a checked ring buffer where `pop` replaces the vacated slot with `$type::default()` so consumed
+/// secrets like keys don't linger in the backing array. `pop` returns a copy `Option<$type>` since the slot is cleared.
+/// The oldest element overwritten by `push` when full is scrubbed as well. Methods moving cursors without `push` and `pop`
+/// like `drain` aren't available. The compiler may elide the scrub of a buffer never read again.
+/// 
+/// ##### `$(#[$attr:meta])*`, `$visibility`, `$name`, `$type`, `$size`
+/// Same as checked ring buffer.
+/// 
+/// #### Example
+/// ```
+/// #[macro_use] extern crate nsrb;
+/// 
+/// nsrb::ring!(@scrub Secrets[u8; 32]);
+/// 
+/// fn main() {
+///     let mut secrets = Secrets::new();
+///     secrets.extend(*b"hunter2");
+///     assert_eq!(secrets.pop(), Some(b'h'));
+///     assert_eq!(unsafe { *secrets.as_ptr() }, 0);
+/// }
+/// ```
+/// 
+/// ## Moving sum
+/// Moving sum ring buffer is a checked ring buffer keeping the sum of its elements updated on each `push` and `pop`
+/// so it's available in O(1), for moving averages. `$type` must implement [AddAssign](core::ops::AddAssign) and [SubAssign](core::ops::SubAssign).
+/// Methods writing elements or moving cursors without `push` and `pop` like `push_if_space` aren't available.
+/// 
+/// The sum must fit in `$type` or it overflows like any integer operation. Floating point sums accumulate rounding
+/// errors over time as values are added then subtracted.
+/// 
+/// ##### `$(#[$attr:meta])*`, `$visibility`, `$name`, `$type`, `$size`
+/// Same as checked ring buffer.
+/// 
+/// #### Example
+/// ```
+/// #[macro_use] extern crate nsrb;
+/// use nsrb::RingLen;
+/// 
+/// nsrb::ring!(@movsum Window[i32; 4]);
+/// 
+/// fn main() {
+///     let mut window = Window::new();
+///     window.extend([1, 2, 3, 4]);
+///     assert_eq!(window.window_sum(), 9);
+///     assert_eq!(window.window_sum() / window.len() as i32, 3);
+/// }
+/// ```
+/// 
+/// #### `$name::window_sum() -> $type`
+/// Returns the sum of all elements in `$name`.
+/// 
+/// ## Rolling hash
+/// Rolling hash ring buffer is a checked ring buffer keeping a polynomial hash of its elements from oldest to newest,
+/// updated in O(1) on each `push`, for [Rabin-Karp](https://en.wikipedia.org/wiki/Rabin%E2%80%93Karp_algorithm) search
+/// over a stream of bytes. `u64` must implement `From<$type>`. Methods writing elements or moving cursors without `push`
+/// and `pop` like `push_if_space` aren't available.
+/// 
+/// Equal hashes don't guarantee equal elements, compare elements to confirm a match.
+/// 
+/// ##### `$(#[$attr:meta])*`, `$visibility`, `$name`, `$type`, `$size`
+/// Same as checked ring buffer.
+/// 
+/// #### Example
+/// ```
+/// #[macro_use] extern crate nsrb;
+/// 
+/// nsrb::ring!(@rollhash SyncWord[u8; 5]);
+/// 
+/// fn main() {
+///     let pattern = SyncWord::hash_of(b"SYNC");
+///     let mut window = SyncWord::new();
+/// 
+///     for &byte in b"..SYNC..".iter() {
+///         window.push(byte);
+///         if window.is_full() && window.rolling_hash() == pattern {
+///             assert!(window.iter().eq(b"SYNC".iter()));
+///         }
+///     }
+/// }
+/// ```
+/// 
+/// #### `$name::rolling_hash() -> u64`
+/// Returns the hash of all elements in `$name` from oldest to newest.
+/// 
+/// #### `$name::hash_of(items : &[$type]) -> u64`
+/// Returns the hash `$name` would have if it contained `items`, to hash a pattern.
+/// 
 /// ## Implementation
 /// Each ring buffer provides those method by default.
 /// 
+/// #### `$name::SIZE`
+/// Size of the backing array, which is `$size` for checked and `$int::MAX + 1` for unchecked. One more than `capacity()`.
+/// 
 /// #### `$name::new()`
-/// Create a new instance of `$name` fixed circular buffer.
+/// Create a new instance of `$name` fixed circular buffer. Panics if `$size` is out of limits.
+/// 
+/// #### `$name::try_new() -> Result<$name, RingError>`
+/// Create a new instance of `$name` fixed circular buffer or returns a [RingError](super::RingError) if `$size` is out of limits.
+/// 
+/// #### `$name::with_capacity_checked(n : usize) -> Result<$name, RingError>`
+/// Same as `try_new` but also returns `RingError::CapacityExceeded` if `$name` can't hold `n` elements, to check a size
+/// read at runtime, like from a configuration, against the fixed `capacity()`. Never allocates.
 /// 
 /// #### `$name::push(item : $type)`
 /// Push an item into `$name` circular buffer.
@@ -117,6 +360,358 @@ SOFTWARE.
 /// #### `$name::pop() -> Option<&$type>`
 /// Returns Some(&`$type`) if buffer contains an element.
 /// 
+/// #### `unsafe $name::from_raw_parts(buffer : [$type; $size], head : usize, tail : usize) -> $name`
+/// Create a checked or unlimited `$name` from a backing array and raw cursors, to build arbitrary states for testing. Modes
+/// with other fields don't have it, since those fields would have to be computed from the elements. Both cursors must
+/// be lower than `$size`, else later calls panic since indexing is bounds checked. Elements are from `tail` to `head`
+/// excluded, wrapping at the end.
+/// 
+/// **The cursors are only checked with [debug_assert] thus release builds accept out of range cursors without panicking.**
+/// 
+/// #### `$name::capacity() -> usize`
+/// Returns the count of element the buffer can hold, which is `$size - 1` for checked and `$int::MAX` for unchecked
+/// since one slot is reserved to tell a full buffer from an empty one. Const function that can be used to size arrays.
+/// 
+/// #### `$name::assert_invariants()`
+/// Panics if the cursors are out of the backing array or if walking the slots from the tail to the head doesn't give
+/// `len()`, to catch corruption by custom `impl` blocks early. Checks use [debug_assert] thus **this method does nothing
+/// in release builds** and can be left in place.
+/// 
+/// #### `$name::occupancy() -> f32`
+/// Returns the fill ratio of `$name` between 0.0 when empty and 1.0 when full, or 0.0 if `capacity()` is 0. Requires the
+/// `float` feature, enabled by default.
+/// 
+/// #### `$name::occupancy_permille() -> u16`
+/// Returns the fill ratio of `$name` between 0 when empty and 1000 when full, without floating point operations.
+/// 
+/// #### `$name::capacity_bytes() -> usize`
+/// Returns the count of bytes `$name` occupies, including cursors. Const function for static memory budgeting.
+/// 
+/// #### `$name::remaining_bytes() -> usize`
+/// Returns the count of bytes that can be pushed before a [u8] `$name` stream buffer is full, which is `capacity() - len()`.
+/// Handy to size a read from a socket or UART without overwriting unread bytes. Only available when `$type` is [u8].
+/// ```compile_fail
+/// #[macro_use] extern crate nsrb;
+/// nsrb::ring!(Words[u32; 16]);
+/// 
+/// fn main() {
+///     let _ = Words::new().remaining_bytes();
+/// }
+/// ```
+/// 
+/// #### `$name::is_full() -> bool`
+/// Returns true if `$name` circular buffer contains `capacity()` elements.
+/// 
+/// #### `$name::iter_mut() -> IterMut<$type>`
+/// Returns an [IterMut](super::IterMut) to modify the elements from oldest to newest.
+/// 
+/// #### `$name::drain() -> Drain<$type>`
+/// Remove all elements and returns a [Drain](super::Drain) iterator yielding them from oldest to newest.
+/// 
+/// #### `$name::nth_oldest_mut(n : usize) -> Option<&mut $type>`, `$name::nth_newest_mut(n : usize) -> Option<&mut $type>`
+/// Returns a mutable reference to the `n`th element from the oldest or from the newest, or None if `n >= len()`.
+/// `nth_newest_mut(0)` is the element just pushed.
+/// 
+/// #### `$name::push_if_space() -> Option<&mut $type>`
+/// Claim the next slot and returns a mutable reference to write the element in place, avoiding a copy of large elements.
+/// Returns None without pushing if `$name` is full. The slot still holds its previous value until written.
+/// 
+/// #### `$name::slide(items : &[$type])`
+/// Push `items` thus `$name` holds the newest `capacity()` elements of the old elements followed by `items`, like
+/// scrolling columns of a spectrogram. Copies the last `capacity()` items at once instead of pushing them one by one if
+/// `items` is at least as long as `capacity()`.
+/// 
+/// #### `$name::as_bytes() -> [u8; $size + 8]`
+/// Returns the exact state of a [u8] `$name` to persist it, like in NVRAM. The tail and head are stored as little endian
+/// [u32] followed by the whole backing array, thus elements keep their physical position unlike `into_array`. Cursors take
+/// 4 bytes each instead of 1 so buffers larger than 256 elements can be saved, hence `$size + 8` rather than `$size + 2`.
+/// Panics if a cursor doesn't fit in [u32], which only happens with sizes allowed by the `no_limit` feature.
+/// 
+/// #### `$name::from_bytes(bytes : &[u8; $size + 8]) -> Option<$name>`
+/// Restore a [u8] `$name` saved with `as_bytes`. Returns None if the tail or head is out of the backing array.
+/// 
+/// #### `$name::split_first() -> Option<(&$type, usize)>`, `$name::split_last() -> Option<(&$type, usize)>`
+/// Remove the oldest or the newest element and returns it with the count of element remaining, or None if empty.
+/// `split_last` isn't available on broadcast ring buffer.
+/// 
+/// #### `$name::shrink_len_to_newest(n : usize)`
+/// Same as `retain_newest` in O(1) by moving the tail, without popping each element nor moving any element.
+/// 
+/// #### `$name::pop_back() -> Option<$type>`
+/// Remove the newest element and returns a copy of it, or None if empty. Counterpart of `pop_front`.
+/// 
+/// #### `$name::clone_from_ring(src : &$name)`
+/// Replace the elements of `$name` with a copy of the elements of `src` in FIFO order, starting at the beginning of the
+/// backing array, to snapshot into an existing buffer. Slots after the copied elements keep their previous value. `self`
+/// is the destination like with [Clone::clone_from]. Not available on broadcast ring buffer since readers of `self` would
+/// skip or read again elements.
+/// 
+/// #### `$name::dedup_by(same : FnMut(&$type, &$type) -> bool)`
+/// Remove consecutive elements for which `same` returns true, keeping the oldest of each run in FIFO order, like
+/// [Vec::dedup_by](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.dedup_by). `same` gets the element then the
+/// previous element kept.
+/// 
+/// #### `$name::split_off(index : usize) -> $name`
+/// Move the elements from `index` to the newest into a new `$name` and keep the `index` oldest elements, like [Vec::split_off](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.split_off).
+/// Panics if `index > len()`.
+/// 
+/// #### `$name::replace(index : usize, value : $type) -> Option<$type>`
+/// Overwrite the element at `index` from the oldest element with `value` and returns the previous element, or None without
+/// writing if `index >= len()`.
+/// 
+/// #### `$name::swap_remove(index : usize) -> Option<$type>`
+/// Remove the element at `index` from the oldest element in O(1) by replacing it with the newest element, thus order isn't preserved.
+/// Returns None if `index >= len()`.
+/// 
+/// #### `$name::remove(index : usize) -> Option<$type>`
+/// Remove the element at `index` from the oldest element and shift the newer elements toward it in O(n), preserving order.
+/// Returns None if `index >= len()`.
+/// 
+/// #### `$name::insert(index : usize, item : $type) -> Result<(), $type>`
+/// Insert `item` at `index` from the oldest element and shift the newer elements toward the head in O(n), preserving order.
+/// Returns `Err(item)` without overwriting if `$name` is full. Panics if `index > len()`.
+/// 
+/// #### `$name::reset()`
+/// Returns `$name` to its `new()` state, removing all elements and setting every slot of the backing array to
+/// `$type::default()` so previous content can't leak through raw reads. Broadcast readers are unsubscribed.
+/// 
+/// #### `$name::shrink_to_fit()`
+/// Does nothing since the backing array is fixed at declaration thus `capacity()` can't grow nor shrink. Provided for code
+/// written against [Vec](https://doc.rust-lang.org/std/vec/struct.Vec.html#method.shrink_to_fit) or VecDeque. Use
+/// `retain_newest` or `shrink_len_to_newest` to drop elements instead.
+/// 
+/// #### `$name::push_reporting(item : $type) -> bool`
+/// Same as `push` but returns true if the oldest element was overwritten because `$name` was full.
+/// 
+/// #### `$name::shift_in(item : $type) -> Option<$type>`
+/// Same as `push` but returns the oldest element shifted out if `$name` was full, like a shift register or tap delay line.
+/// 
+/// #### `$name::push_if_changed(item : $type) -> bool`
+/// Push `item` only if it differs from the newest element and returns true if pushed, to record changes without repeats.
+/// Always pushes into an empty `$name`. `$type` must implement [PartialEq].
+/// 
+/// #### `$name::emplace(make : FnOnce() -> $type)`
+/// Push the element created by `make` directly into its slot, overwriting the oldest element if full.
+/// 
+/// #### `$name::try_emplace(make : FnOnce() -> $type) -> bool`
+/// Same as `emplace` but returns false without calling `make` if `$name` is full.
+/// 
+/// #### `$name::fill_from_fn(make : FnMut() -> $type)`
+/// Push the elements returned by `make` until `$name` is full. Doesn't call `make` if already full.
+/// 
+/// #### `$name::clear_and_fill(item : $type)`
+/// Same as `reset` then push `item` until `$name` is full, to prime a window with a known baseline.
+/// 
+/// #### `$name::extend_counting(iter : IntoIterator<Item = $type>) -> usize`
+/// Push each item of an iterator and returns the count of element that were overwritten.
+/// 
+/// #### `$name::push_iter_bounded(iter : IntoIterator<Item = $type>, max : usize) -> usize`
+/// Push at most `max` items of an iterator and returns the count pushed, to meter ingestion. Items after `max` aren't
+/// consumed. Overwrites the oldest elements once full like `push`.
+/// 
+/// #### `$name::push_with_headroom(item : $type, headroom : usize) -> Result<(), $type>`
+/// Push `item` only if `headroom` slots stay free afterward, thus `capacity() - headroom` is the effective limit, like a
+/// jitter buffer keeping space for bursts. Returns `item` as error without pushing otherwise. Never overwrites.
+/// 
+/// #### `$name::push_all_or_none(items : &[$type]) -> Result<(), ()>`
+/// Push all `items` if they fit without overwriting any element, else push nothing and returns `Err`.
+/// 
+/// #### `$name::retain_newest(n : usize)`
+/// Pop the oldest elements until at most `n` elements remain. Pops one element at a time thus it is available in each mode,
+/// use `shrink_len_to_newest` in O(1) where available.
+/// 
+/// #### `$name::drain_to_slice_exact(out : &mut [$type]) -> bool`
+/// Pop exactly `out.len()` elements into `out` in FIFO order and returns true, or returns false without popping if
+/// `$name` contains less elements, for readers of fixed blocks.
+/// 
+/// #### `$name::drain_into(other : &mut $name) -> usize`
+/// Pop elements in FIFO order and push them into `other` until `self` is empty or `other` is full, and returns the count
+/// transferred, like flushing a staging buffer into a transmit buffer. Elements of `other` are never overwritten and
+/// elements that didn't fit stay in `self`.
+/// 
+/// #### `$name::collect_into(out : &mut [$type]) -> usize`
+/// Pop as many elements as fit into `out` in FIFO order and returns the count popped, to drain into a scratch buffer.
+/// Elements of `out` after the count are left untouched.
+/// 
+/// #### `$name::peek_while(pred : FnMut(&$type) -> bool) -> usize`
+/// Returns the count of oldest elements `pop_while` would pop with `pred`, without popping them.
+/// 
+/// #### `$name::pop_while(pred : FnMut(&$type) -> bool) -> usize`
+/// Pop the oldest elements while `pred` returns true and returns the count popped, like expiring timers earlier than now.
+/// Stops at the first element for which `pred` returns false.
+/// 
+/// #### `$name::position(item : &$type) -> Option<usize>`
+/// Returns the index from the oldest element of the first element equal to `item`. `$type` must implement [PartialEq].
+/// 
+/// #### `$name::find_byte(byte : $type) -> Option<usize>`
+/// Same as `position` taking the delimiter by value, for line based protocols over a [u8] `$name`. A complete record is
+/// the `index + 1` oldest elements, delimiter included.
+/// 
+/// #### `$name::as_slice_from(consumed : usize) -> (&[$type], &[$type])`
+/// Returns the elements after the `consumed` oldest ones as 2 slices since they may wrap, without popping them. A
+/// parser can resume from a checkpoint and only pop once a record is complete. Both slices are empty if `consumed >= len()`.
+/// 
+/// #### `$name::rposition(item : &$type) -> Option<usize>`
+/// Returns the index from the oldest element of the newest element equal to `item`. `$type` must implement [PartialEq].
+/// 
+/// #### `$name::histogram<const B : usize>() -> [usize; B]`
+/// Returns the count of each value `0..B` among the elements, like the distribution of the last status codes. Values
+/// out of range, negative included, are counted in the last bucket. `usize` must implement [TryFrom] `$type`.
+/// 
+/// #### `$name::fold<B>(init : B, f : FnMut(B, &$type) -> B) -> B`
+/// Combine the elements from oldest to newest into an accumulator starting at `init`, to compute a sum or a checksum.
+/// 
+/// #### `$name::eq_slice_by(other : &[$type], eq : FnMut(&$type, &$type) -> bool) -> bool`
+/// Returns true if `other` has the same length and each element from oldest to newest is equal to the element of `other`
+/// at the same index according to `eq`, to compare elements ignoring some fields like timestamps.
+/// 
+/// #### `$name::contains_by(item : &$type, eq : FnMut(&$type, &$type) -> bool) -> bool`
+/// Returns true if `eq` returns true for an element and `item`, for types without [PartialEq] or compared on a field.
+/// 
+/// #### `$name::last_distinct() -> Option<&$type>`
+/// Returns the newest element different from the element pushed before it, which is the first of the trailing duplicates,
+/// like the last change of a debounced input. Returns None if there's no change since the oldest element. `$type` must
+/// implement [PartialEq].
+/// 
+/// #### `$name::contains_subsequence(pattern : &[$type]) -> bool`
+/// Returns true if `pattern` appears as consecutive elements from oldest to newest, like a sync word in a stream of
+/// bytes. An empty `pattern` is always found. `$type` must implement [PartialEq].
+/// 
+/// #### `$name::min_by_key(f : FnMut(&$type) -> K) -> Option<&$type>`, `$name::max_by_key(f : FnMut(&$type) -> K) -> Option<&$type>`
+/// Returns the element with the smallest or largest key returned by `f`, or None if empty. If several elements are equally
+/// minimum, the oldest is returned and if several are equally maximum, the newest is returned.
+/// 
+/// #### `$name::is_sorted() -> bool`
+/// Returns true if the elements are non-decreasing from oldest to newest, to check a `$name` before `binary_search`.
+/// `$type` must implement [PartialOrd].
+/// 
+/// #### `$name::binary_search(item : &$type) -> Result<usize, usize>`
+/// Binary search `item` in a `$name` sorted from oldest to newest, like [slice::binary_search]. Returns `Ok` with the index
+/// from the oldest element if found, else `Err` with the index where it could be inserted. `$type` must implement [Ord].
+/// 
+/// #### `$name::oldest() -> Option<&$type>`, `$name::newest() -> Option<&$type>`
+/// Returns the oldest element, which is the next one popped, or the newest element, which is the last one pushed, without
+/// popping it. Returns None if empty.
+/// 
+/// #### `$name::front() -> Option<&$type>`, `$name::back() -> Option<&$type>`
+/// Same as `oldest` and `newest`, named like [VecDeque](https://doc.rust-lang.org/std/collections/struct.VecDeque.html).
+/// Elements are pushed at the back and popped from the front.
+/// 
+/// #### `$name::pop_front() -> Option<$type>`
+/// Same as `pop` but returns a copy of the element, thus the same type for each mode. Counterpart of `pop_back`.
+/// 
+/// #### `$name::pop_with(f : FnOnce($type)) -> bool`
+/// Pop the oldest element and hand it to `f`, like returning a buffer handle to a caller-managed pool for reuse. Returns
+/// false without calling `f` if `$name` is empty.
+/// 
+/// #### `$name::push_spin(item : $type, backoff : FnMut(&mut $name))`, `$name::pop_spin(backoff : FnMut(&mut $name)) -> $type`
+/// Wait until `$name` has room to push `item` without overwriting, or until an element can be popped, calling `backoff`
+/// then [spin_loop](core::hint::spin_loop) between each try. `backoff` gets `$name` to make progress, like draining to or
+/// filling from a device, or to yield. Those never return if `backoff` doesn't eventually pop, respectively push, since
+/// nothing else can modify `$name` while it's borrowed.
+/// 
+/// #### `$name::tap(n : usize) -> Option<&$type>`
+/// Returns the sample pushed `n` samples ago, where 0 is the newest, like a tap of a delay line for FIR filters.
+/// Returns None if `n >= len()`.
+/// 
+/// #### `$name::iter() -> Iter<$type>`
+/// Returns an [Iter](super::Iter) over the elements from oldest to newest. Use `iter().rev()` to iterate from newest to oldest.
+/// 
+/// #### `$name::peek_slice(n : usize) -> Option<&[$type]>`
+/// Returns the `n` oldest elements as a single slice without popping, or None if they wrap at the end of the backing array
+/// or if `n > len()`. A fast path for parsers that fall back on `items()` or `iter()`.
+/// 
+/// #### `$name::try_peek_array<const M : usize>() -> Option<[$type; M]>`
+/// Returns a copy of the `M` oldest elements without popping, or None if `$name` contains less than `M` elements.
+/// 
+/// #### `$name::items(out : &mut [$type]) -> usize`
+/// Copy the elements from oldest to newest at the start of `out` without popping and returns the count copied, which is
+/// the smallest of `len()` and `out.len()`.
+/// 
+/// #### `$name::map_into<U, R : FromIterator<U>>(f : FnMut(&$type) -> U) -> R`
+/// Apply `f` to each element from oldest to newest and collect the results into `R`, usually another ring buffer of `U`
+/// declared with `ring!`. Only the newest results are kept if `R` is smaller.
+/// 
+/// #### `$name::flat_map_into<U, R : FromIterator<U>>(f : FnMut(&$type) -> IntoIterator<Item = U>) -> R`
+/// Same as `map_into` but `f` expands each element into zero or more results, like decoding tokens into runs of bytes.
+/// Only the newest results are kept if `R` is smaller.
+/// 
+/// #### `$name::into_ring<R : FromIterator<$type>>() -> R`
+/// Convert into another ring buffer of `$type` keeping the elements from oldest to newest, like migrating a checked
+/// `$name[$type; 256]` to an `@unchecked(u8)` one with the same capacity, or back. Only the newest elements are kept if
+/// `R` is smaller.
+/// 
+/// #### `$name::zip_with(other : &$name, f : FnMut(&$type, &$type) -> $type) -> $name`
+/// Returns a new `$name` combining elements of `self` and `other` at the same index from the oldest element with `f`.
+/// Stops at the shorter buffer thus the result has the smallest `len()` of both.
+/// 
+/// #### `$name::interleave(other : &$name) -> $name`
+/// Returns a new `$name` alternating elements of `self` and `other` from oldest to newest, then the remaining elements
+/// of the longer buffer. Only the newest elements are kept if the result exceeds `capacity()`.
+/// 
+/// #### `$name::merge_sorted(other : &$name) -> $name`
+/// Returns a new `$name` merging the elements of `self` and `other`, both sorted from oldest to newest, into a sorted
+/// buffer like merging 2 time ordered logs. Equal elements of `self` come first. Unlike `interleave`, only the smallest
+/// elements are kept if the result exceeds `capacity()`. `$type` must implement [Ord].
+/// 
+/// #### `$name::split_at(index : usize) -> (impl Iterator<Item = &$type>, impl Iterator<Item = &$type>)`
+/// Returns an iterator over the elements before `index` and another from `index` to the newest element, in FIFO order.
+/// Panics if `index > len()`.
+/// 
+/// #### `$name::hex() -> HexDisplay`
+/// Returns a [HexDisplay](super::HexDisplay) printing the bytes of a [u8] `$name` in FIFO order as hexadecimal like
+/// `DE AD BE EF`. Only available when `$type` is [u8].
+/// 
+/// #### `$name::as_ptr() -> *const $type`, `$name::as_mut_ptr() -> *mut $type`
+/// Returns a raw pointer to the start of the backing array of `capacity() + 1` elements, to program a DMA descriptor for example.
+/// The pointer is only valid while `$name` isn't moved. Writing through it doesn't move the cursors and the compiler
+/// assumes nothing else writes to the buffer, use [read_volatile](core::ptr::read_volatile) if hardware writes to it concurrently.
+/// 
+/// #### `$name::physical_head() -> usize`, `$name::physical_tail() -> usize`
+/// Returns the index in the backing array where the next element will be pushed and where the oldest element is.
+/// 
+/// #### `$name::into_array() -> ([$type; $size], usize)`
+/// Consume `$name` and returns the backing array rotated so elements are at the front in FIFO order, with the count of element.
+/// 
+/// ## Traits
+/// Each ring buffer implements those traits by default.
+/// 
+/// #### `RingLen`
+/// Returns the count of element with `len()` and if there's none with `is_empty()`. Import [RingLen](super::RingLen) to
+/// call them. An `impl` block can still define its own `len()` or `is_empty()`, like the `Extra` example, which takes
+/// precedence over the trait.
+/// 
+/// #### `RingCursors`
+/// Returns the raw cursors of checked ring buffers with `head()` and `tail()`, same as `physical_head()` and
+/// `physical_tail()`. Import [RingCursors](super::RingCursors) to call them. Like `RingLen`, an `impl` block can still
+/// define its own `head()` or `tail()`.
+/// 
+/// #### `Extend<$type>`
+/// Push each item of an iterator into `$name`, overwriting the oldest elements once full.
+/// 
+/// #### `arbitrary::Arbitrary`
+/// Generate an arbitrary `$name` with elements and cursors position from fuzzing data. Requires the `arbitrary` feature.
+/// 
+/// #### `zeroize::Zeroize`
+/// Overwrite the whole backing array with zeroes and empty `$name`, for buffers holding secrets. Requires the `zeroize`
+/// feature. Since `$name` can derive [Copy], `ZeroizeOnDrop` isn't implemented and `zeroize::Zeroizing<$name>` must be used
+/// to zeroize on drop.
+/// 
+/// #### `Index<usize>`
+/// Returns the element at an index from the oldest element with `rb[i]`, like a slice in FIFO order. Elements aren't
+/// contiguous in memory thus no slice can be borrowed. Panics if the index is `len()` or more.
+/// 
+/// #### `IntoIterator for $name`, `IntoIterator for &$name`
+/// Iterate over the elements from oldest to newest with `for item in rb` or `for item in &rb`, same as `iter()`.
+/// Both iterators have an exact `size_hint()` of `len()` so `collect()` allocates once.
+/// 
+/// #### `FromIterator<$type>`
+/// Create a new `$name` from an iterator with `collect()`, keeping only the newest elements if the iterator
+/// yields more than the capacity.
+/// 
+/// Other traits like [Clone] and [Copy] can be derived with `$(#[$attr:meta])*`. Since `$type` is [Copy], a derived
+/// [Clone] copies the backing array and cursors as is.
+/// 
 /// ## Extra
 /// Extra implementation that can be added if needed.
 /// 
@@ -151,63 +746,48 @@ macro_rules! ring {
 
         #[allow(dead_code)]
         impl $name {
-            pub fn new() -> $name {             
-            
-                #[cfg(not(feature = "no_limit"))]
-                assert!($size as usize >= $crate::NSRB_LOWER_LIMIT);
-
-                #[cfg(not(feature = "no_limit"))]
-                assert!($size as usize <= $crate::NSRB_UPPER_LIMIT);
+            pub fn try_new() -> Result<$name, $crate::RingError> {
+                $crate::ring_size_limits!($size);
 
-                $name {
+                Ok($name {
                     tail: 0,
                     head: 0,
                     buffer: [<$type>::default(); $size],
-                }
-            }
-
-            #[inline(always)]
-            pub fn push(&mut self, item : $type) {
-                self.buffer[self.head] = item;
-                self.push_head();
-            }
-
-            #[inline(always)]
-            pub fn pop(&mut self) -> Option<&$type> {
-                
-                if self.tail != self.head {
-                    let tail = self.tail;
-                    self.push_tail();
-                    Some(&self.buffer[tail])
-                } else {
-                    None
-                }
+                })
             }
+        }
 
-            #[inline(always)]
-            fn push_head(&mut self) {
-
-                if self.head >= $size - 1 {
-                    self.head = 0;
-                } else {
-                    self.head += 1;
-                }
-
-                if self.head == self.tail {
-                    self.push_tail();
-                }
+        $crate::ring_checked_push_pop!($name[$type; $size]);
+        $crate::ring_from_raw_parts!($name[$type; $size]);
+        $crate::ring_checked_core!($name[$type; $size]);
+        $crate::ring_core!($name[$type; $size]);
+        $crate::ring_core_mut!($name[$type; $size]);
+        $crate::ring_core_splice!($name[$type; $size]);
+    };
+    (@unlimited $(#[$attr:meta])* $visibility : vis $name : ident[$type : ty; $size : expr]) => {
+        $(
+            #[$attr]
+        )*
+        #[allow(dead_code)]
+        $visibility struct $name { tail : usize, head : usize, buffer : [$type; $size], }
 
+        #[allow(dead_code)]
+        impl $name {
+            pub fn try_new() -> Result<$name, $crate::RingError> {
+                Ok($name {
+                    tail: 0,
+                    head: 0,
+                    buffer: [<$type>::default(); $size],
+                })
             }
+        }
 
-            #[inline(always)]
-            fn push_tail(&mut self) {
-                if self.tail >= $size - 1 {
-                    self.tail = 0;
-                } else {
-                    self.tail += 1;
-                }
-            }
-        }   
+        $crate::ring_checked_push_pop!($name[$type; $size]);
+        $crate::ring_from_raw_parts!($name[$type; $size]);
+        $crate::ring_checked_core!($name[$type; $size]);
+        $crate::ring_core!($name[$type; $size]);
+        $crate::ring_core_mut!($name[$type; $size]);
+        $crate::ring_core_splice!($name[$type; $size]);
     };
     (@unchecked($int:ty) $(#[$attr:meta])* $visibility : vis $name : ident[$type : ty]) => {
         $(
@@ -220,79 +800,2990 @@ macro_rules! ring {
             buffer : [$type; <$int>::MAX as usize + 1],
         }
 
+        $crate::ring_stack_limit!($int, $type);
+
         #[allow(dead_code)]
         impl $name {
-            pub fn new() -> $name {
+            pub fn try_new() -> Result<$name, $crate::RingError> {
+                $crate::ring_size_limits!(<$int>::MAX as usize);
 
-                #[cfg(not(feature = "no_limit"))]
-                assert!(<$int>::MAX as usize <= $crate::NSRB_UPPER_LIMIT);
-
-                $name {
+                Ok($name {
                     tail: 0,
                     head: 0,
                     buffer: [<$type>::default(); <$int>::MAX as usize + 1],
-                }
+                })
             }
 
             #[inline(always)]
             pub fn push(&mut self, item : $type) {
                 self.buffer[self.head as usize] = item;
-                self.head += 1;
+                self.push_head();
+            }
+
+            pub fn push_checked(&mut self, item : $type) {
+                self.buffer[self.head as usize] = item;
+
+                self.head = if self.head == <$int>::MAX { 0 } else { self.head + 1 };
                 if self.head == self.tail {
-                    self.tail += 1;
+                    self.tail = if self.tail == <$int>::MAX { 0 } else { self.tail + 1 };
+                }
+            }
+
+            #[inline(always)]
+            pub fn pop(&mut self) -> Option<&$type> {
+                if self.tail != self.head {
+                    let tail = self.tail;
+                    self.push_tail();
+                    Some(&self.buffer[tail as usize])
+                } else {
+                    None
+                }
+            }
+
+            #[inline(always)]
+            pub const fn capacity() -> usize {
+                <$int>::MAX as usize
+            }
+
+            #[inline(always)]
+            fn index(&self, index : usize) -> usize {
+                self.tail.wrapping_add(index as $int) as usize
+            }
+
+            #[inline(always)]
+            fn push_head(&mut self) {
+                self.head = self.head.wrapping_add(1);
+                if self.head == self.tail {
+                    self.tail = self.tail.wrapping_add(1);
+                }
+            }
+
+            #[inline(always)]
+            fn pop_head(&mut self) {
+                self.head = self.head.wrapping_sub(1);
+            }
+
+            #[inline(always)]
+            fn push_tail(&mut self) {
+                self.tail = self.tail.wrapping_add(1);
+            }
+        }
+
+        impl $crate::RingLen for $name {
+            #[inline(always)]
+            fn len(&self) -> usize {
+                self.head.wrapping_sub(self.tail) as usize
+            }
+        }
+
+        $crate::ring_core!($name[$type; <$int>::MAX as usize + 1]);
+        $crate::ring_core_mut!($name[$type; <$int>::MAX as usize + 1]);
+        $crate::ring_core_splice!($name[$type; <$int>::MAX as usize + 1]);
+    };
+    (@broadcast($readers : expr) $(#[$attr:meta])* $visibility : vis $name : ident[$type : ty; $size : expr]) => {
+        $(
+            #[$attr]
+        )*
+        #[allow(dead_code)]
+        $visibility struct $name {
+            tail : usize,
+            head : usize,
+            written : usize,
+            buffer : [$type; $size],
+            readers : [Option<usize>; $readers],
+        }
+
+        #[allow(dead_code)]
+        impl $name {
+            pub fn try_new() -> Result<$name, $crate::RingError> {
+                $crate::ring_size_limits!($size);
+
+                Ok($name {
+                    tail: 0,
+                    head: 0,
+                    written: 0,
+                    buffer: [<$type>::default(); $size],
+                    readers: [None; $readers],
+                })
+            }
+
+            pub fn subscribe(&mut self) -> Option<usize> {
+                let reader = self.readers.iter().position(|r| r.is_none())?;
+                self.readers[reader] = Some(self.written);
+                Some(reader)
+            }
+
+            pub fn unsubscribe(&mut self, reader : usize) {
+                self.readers[reader] = None;
+            }
+
+            pub fn cursor(&self) -> $crate::Cursor {
+                $crate::Cursor::from_sequence(self.written.wrapping_sub($crate::RingLen::len(self)))
+            }
+
+            pub fn read_from(&self, cursor : $crate::Cursor) -> Result<(&[$type], &[$type], $crate::Cursor), $crate::Lagged> {
+                let len = $crate::RingLen::len(self);
+                let behind = self.written.wrapping_sub(cursor.sequence());
+
+                if behind > len {
+                    Err($crate::Lagged { missed : behind - len, cursor : self.cursor() })
+                } else {
+                    let (a, b) = self.slices(len - behind, len);
+                    Ok((a, b, $crate::Cursor::from_sequence(self.written)))
+                }
+            }
+
+            pub fn receive(&mut self, reader : usize) -> Result<Option<&$type>, $crate::Lagged> {
+                let sequence = match self.readers[reader] {
+                    Some(sequence) => sequence,
+                    None => return Ok(None),
+                };
+
+                let len = $crate::RingLen::len(self);
+                let behind = self.written.wrapping_sub(sequence);
+
+                if behind > len {
+                    let cursor = self.cursor();
+                    self.readers[reader] = Some(cursor.sequence());
+                    Err($crate::Lagged { missed : behind - len, cursor })
+                } else if behind == 0 {
+                    Ok(None)
+                } else {
+                    self.readers[reader] = Some(sequence.wrapping_add(1));
+                    Ok(Some(&self.buffer[self.index(len - behind)]))
+                }
+            }
+        }
+
+        $crate::ring_checked_push_pop!($name[$type; $size], written);
+        $crate::ring_checked_core!($name[$type; $size]);
+        $crate::ring_core!($name[$type; $size]);
+        $crate::ring_core_mut!($name[$type; $size], written);
+    };
+    (@volatile $(#[$attr:meta])* $visibility : vis $name : ident[$type : ty; $size : expr]) => {
+        $(
+            #[$attr]
+        )*
+        #[allow(dead_code)]
+        $visibility struct $name { tail : usize, head : usize, buffer : [$type; $size], }
+
+        #[allow(dead_code)]
+        impl $name {
+            pub fn try_new() -> Result<$name, $crate::RingError> {
+                $crate::ring_size_limits!($size);
+
+                Ok($name {
+                    tail: 0,
+                    head: 0,
+                    buffer: [<$type>::default(); $size],
+                })
+            }
+
+            #[inline(always)]
+            pub fn push(&mut self, item : $type) {
+                // Safety : pointer comes from a reference thus is valid and aligned.
+                unsafe { ::core::ptr::write_volatile(&mut self.buffer[self.head], item) };
+                self.push_head();
+            }
+
+            #[inline(always)]
+            pub fn pop(&mut self) -> Option<$type> {
+                
+                if self.tail != self.head {
+                    let tail = self.tail;
+                    self.push_tail();
+                    // Safety : pointer comes from a reference thus is valid and aligned.
+                    Some(unsafe { ::core::ptr::read_volatile(&self.buffer[tail]) })
+                } else {
+                    None
+                }
+            }
+        }
+
+        $crate::ring_checked_core!($name[$type; $size]);
+        $crate::ring_core!($name[$type; $size]);
+        $crate::ring_core_mut!($name[$type; $size]);
+        $crate::ring_core_splice!($name[$type; $size]);
+    };
+    (@align($align : literal) $(#[$attr:meta])* $visibility : vis $name : ident[$type : ty; $size : expr]) => {
+        $(
+            #[$attr]
+        )*
+        #[allow(dead_code)]
+        #[repr(C, align($align))]   // Buffer first so it starts on the alignment boundary
+        $visibility struct $name { buffer : [$type; $size], tail : usize, head : usize, }
+
+        #[allow(dead_code)]
+        impl $name {
+            pub fn try_new() -> Result<$name, $crate::RingError> {
+                $crate::ring_size_limits!($size);
+
+                Ok($name {
+                    buffer: [<$type>::default(); $size],
+                    tail: 0,
+                    head: 0,
+                })
+            }
+        }
+
+        $crate::ring_checked_push_pop!($name[$type; $size]);
+        $crate::ring_checked_core!($name[$type; $size]);
+        $crate::ring_core!($name[$type; $size]);
+        $crate::ring_core_mut!($name[$type; $size]);
+        $crate::ring_core_splice!($name[$type; $size]);
+    };
+    (@scrub $(#[$attr:meta])* $visibility : vis $name : ident[$type : ty; $size : expr]) => {
+        $(
+            #[$attr]
+        )*
+        #[allow(dead_code)]
+        $visibility struct $name { tail : usize, head : usize, buffer : [$type; $size], }
+
+        #[allow(dead_code)]
+        impl $name {
+            pub fn try_new() -> Result<$name, $crate::RingError> {
+                $crate::ring_size_limits!($size);
+
+                Ok($name {
+                    tail: 0,
+                    head: 0,
+                    buffer: [<$type>::default(); $size],
+                })
+            }
+
+            #[inline(always)]
+            pub fn push(&mut self, item : $type) {
+                let full = self.is_full();
+                self.buffer[self.head] = item;
+                self.push_head();
+
+                // The overwritten oldest element is in the reserved slot.
+                if full {
+                    self.buffer[self.head] = <$type>::default();
+                }
+            }
+
+            #[inline(always)]
+            pub fn pop(&mut self) -> Option<$type> {
+                
+                if self.tail != self.head {
+                    let tail = self.tail;
+                    self.push_tail();
+                    Some(::core::mem::take(&mut self.buffer[tail]))
+                } else {
+                    None
+                }
+            }
+        }
+
+        $crate::ring_checked_core!($name[$type; $size]);
+        $crate::ring_core!($name[$type; $size]);
+    };
+    (@movsum $(#[$attr:meta])* $visibility : vis $name : ident[$type : ty; $size : expr]) => {
+        $(
+            #[$attr]
+        )*
+        #[allow(dead_code)]
+        $visibility struct $name { tail : usize, head : usize, sum : $type, buffer : [$type; $size], }
+
+        #[allow(dead_code)]
+        impl $name {
+            pub fn try_new() -> Result<$name, $crate::RingError> {
+                $crate::ring_size_limits!($size);
+
+                Ok($name {
+                    tail: 0,
+                    head: 0,
+                    sum: <$type>::default(),
+                    buffer: [<$type>::default(); $size],
+                })
+            }
+
+            #[inline(always)]
+            pub fn push(&mut self, item : $type) {
+                if self.is_full() {
+                    self.sum -= self.buffer[self.tail];
+                }
+                self.sum += item;
+                self.buffer[self.head] = item;
+                self.push_head();
+            }
+
+            #[inline(always)]
+            pub fn pop(&mut self) -> Option<&$type> {
+                
+                if self.tail != self.head {
+                    let tail = self.tail;
+                    self.sum -= self.buffer[tail];
+                    self.push_tail();
+                    Some(&self.buffer[tail])
+                } else {
+                    None
+                }
+            }
+
+            #[inline(always)]
+            pub fn window_sum(&self) -> $type {
+                self.sum
+            }
+        }
+
+        $crate::ring_checked_core!($name[$type; $size]);
+        $crate::ring_core!($name[$type; $size]);
+    };
+    (@rollhash $(#[$attr:meta])* $visibility : vis $name : ident[$type : ty; $size : expr]) => {
+        $(
+            #[$attr]
+        )*
+        #[allow(dead_code)]
+        $visibility struct $name { tail : usize, head : usize, hash : u64, buffer : [$type; $size], }
+
+        #[allow(dead_code)]
+        impl $name {
+            /// Base of the polynomial hash.
+            const HASH_BASE : u64 = 257;
+
+            /// Weight of the oldest element of a full buffer.
+            const HASH_FULL_WEIGHT : u64 = Self::HASH_BASE.wrapping_pow(($size as u32).saturating_sub(2));
+
+            pub fn try_new() -> Result<$name, $crate::RingError> {
+                $crate::ring_size_limits!($size);
+
+                Ok($name {
+                    tail: 0,
+                    head: 0,
+                    hash: 0,
+                    buffer: [<$type>::default(); $size],
+                })
+            }
+
+            #[inline(always)]
+            pub fn push(&mut self, item : $type) {
+                if self.is_full() {
+                    self.hash = self.hash.wrapping_sub(u64::from(self.buffer[self.tail]).wrapping_mul(Self::HASH_FULL_WEIGHT));
+                }
+                self.hash = self.hash.wrapping_mul(Self::HASH_BASE).wrapping_add(u64::from(item));
+                self.buffer[self.head] = item;
+                self.push_head();
+            }
+
+            #[inline(always)]
+            pub fn pop(&mut self) -> Option<&$type> {
+                
+                if self.tail != self.head {
+                    let tail = self.tail;
+                    let weight = Self::HASH_BASE.wrapping_pow($crate::RingLen::len(self) as u32 - 1);
+                    self.hash = self.hash.wrapping_sub(u64::from(self.buffer[tail]).wrapping_mul(weight));
+                    self.push_tail();
+                    Some(&self.buffer[tail])
+                } else {
+                    None
                 }
             }
 
-            #[inline(always)]
-            pub fn pop(&mut self) -> Option<&$type> {
-                if self.tail != self.head {
-                    let tail = self.tail;
-                    self.tail += 1;
-                    Some(&self.buffer[tail as usize])
-                } else {
-                    None
-                }
-            }
-        }   
-    };
+            #[inline(always)]
+            pub fn rolling_hash(&self) -> u64 {
+                self.hash
+            }
+
+            pub fn hash_of(items : &[$type]) -> u64 {
+                items.iter().fold(0u64, |hash, &item| hash.wrapping_mul(Self::HASH_BASE).wrapping_add(u64::from(item)))
+            }
+        }
+
+        $crate::ring_checked_core!($name[$type; $size]);
+        $crate::ring_core!($name[$type; $size]);
+    };
+
+}
+
+/// Returns an error from `try_new` if `$size` is out of [NSRB_LOWER_LIMIT](super::NSRB_LOWER_LIMIT) and
+/// [NSRB_UPPER_LIMIT](super::NSRB_UPPER_LIMIT), removed with the `no_limit` feature of nsrb rather than of the calling crate.
+#[cfg(not(feature = "no_limit"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! ring_size_limits {
+    ($size : expr) => {
+        if ($size as usize) < $crate::NSRB_LOWER_LIMIT {
+            return Err($crate::RingError::TooSmall);
+        }
+
+        if $size as usize > $crate::NSRB_UPPER_LIMIT {
+            return Err($crate::RingError::TooLarge);
+        }
+    };
+}
+
+#[cfg(feature = "no_limit")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! ring_size_limits {
+    ($size : expr) => {};
+}
+
+/// `push` and `pop` of checked ring buffer arms that store elements as is.
+/// 
+/// `$written` is the field counting pushed elements of arms that have one, like broadcast.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! ring_checked_push_pop {
+    ($name : ident[$type : ty; $size : expr] $(, $written : ident)?) => {
+        #[allow(dead_code)]
+        impl $name {
+            #[inline(always)]
+            pub fn push(&mut self, item : $type) {
+                self.buffer[self.head] = item;
+                $(self.$written = self.$written.wrapping_add(1);)?
+                self.push_head();
+            }
+
+            #[inline(always)]
+            pub fn pop(&mut self) -> Option<&$type> {
+                
+                if self.tail != self.head {
+                    let tail = self.tail;
+                    self.push_tail();
+                    Some(&self.buffer[tail])
+                } else {
+                    None
+                }
+            }
+        }
+    };
+}
+
+/// `from_raw_parts` of arms declaring only `tail`, `head` and `buffer`, since other fields like `sum` would
+/// have to be computed from the elements.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! ring_from_raw_parts {
+    ($name : ident[$type : ty; $size : expr]) => {
+        #[allow(dead_code)]
+        impl $name {
+            /// # Safety
+            /// `head` and `tail` must be lower than `$size`, else later calls panic on out of bounds indexing. This is
+            /// only checked with [debug_assert], not in release builds.
+            pub unsafe fn from_raw_parts(buffer : [$type; $size], head : usize, tail : usize) -> $name {
+                debug_assert!(head < $size);
+                debug_assert!(tail < $size);
+
+                $name { tail, head, buffer }
+            }
+        }
+    };
+}
+
+/// Implementation shared by checked ring buffer arms.
+/// 
+/// Arms must declare `tail` and `head` as [usize] and implement their own `new`, `push` and `pop`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! ring_checked_core {
+    ($name : ident[$type : ty; $size : expr]) => {
+        // Cursors arithmetic needs at least 1 element, even with the `no_limit` feature.
+        const _ : () = assert!($size as usize >= 1, "Ring buffer size must be at least 1.");
+
+        #[allow(dead_code)]
+        impl $name {
+            /// Power of 2 sizes wrap cursors with a mask instead of a comparison.
+            const POW2 : bool = ($size as usize).is_power_of_two();
+
+            #[inline(always)]
+            pub const fn capacity() -> usize {
+                $size - 1
+            }
+
+            #[inline(always)]
+            fn index(&self, index : usize) -> usize {
+                let index = self.tail + index;
+                if Self::POW2 {
+                    index & ($size - 1)
+                } else if index >= self.buffer.len() {
+                    index - self.buffer.len()
+                } else {
+                    index
+                }
+            }
+
+            #[inline(always)]
+            fn push_head(&mut self) {
+
+                if Self::POW2 {
+                    self.head = (self.head + 1) & ($size - 1);
+                } else if self.head >= $size - 1 {
+                    self.head = 0;
+                } else {
+                    self.head += 1;
+                }
+
+                if self.head == self.tail {
+                    self.push_tail();
+                }
+
+            }
+
+            #[inline(always)]
+            fn pop_head(&mut self) {
+                if Self::POW2 {
+                    self.head = self.head.wrapping_sub(1) & ($size - 1);
+                } else if self.head == 0 {
+                    self.head = $size - 1;
+                } else {
+                    self.head -= 1;
+                }
+            }
+
+            #[inline(always)]
+            fn push_tail(&mut self) {
+                if Self::POW2 {
+                    self.tail = (self.tail + 1) & ($size - 1);
+                } else if self.tail >= $size - 1 {
+                    self.tail = 0;
+                } else {
+                    self.tail += 1;
+                }
+            }
+        }
+
+        impl $crate::RingLen for $name {
+            #[inline(always)]
+            fn len(&self) -> usize {
+                if self.tail > self.head {
+                    $size + self.head - self.tail
+                } else {
+                    self.head - self.tail
+                }
+            }
+        }
+
+        impl $crate::RingCursors for $name {
+            #[inline(always)]
+            fn head(&self) -> usize {
+                self.head
+            }
+
+            #[inline(always)]
+            fn tail(&self) -> usize {
+                self.tail
+            }
+        }
+    };
+}
+
+/// Methods writing elements or moving cursors without `push` and `pop`.
+/// 
+/// Not included by arms maintaining extra state in `push` and `pop`. `$written` is the field counting pushed elements
+/// of arms that have one, like broadcast.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! ring_core_mut {
+    ($name : ident[$type : ty; $size : expr] $(, $written : ident)?) => {
+        #[allow(dead_code)]
+        impl $name {
+            pub fn iter_mut(&mut self) -> $crate::IterMut<'_, $type> {
+                let count = $crate::RingLen::len(self);
+                let start = self.index(0);
+
+                if start + count <= self.buffer.len() {
+                    $crate::IterMut::new(&mut self.buffer[start..start + count], &mut [])
+                } else {
+                    let end = start + count - self.buffer.len();
+                    let (back, front) = self.buffer.split_at_mut(start);
+                    $crate::IterMut::new(front, &mut back[..end])
+                }
+            }
+
+            pub fn drain(&mut self) -> $crate::Drain<'_, $type> {
+                let count = $crate::RingLen::len(self);
+                let start = self.index(0);
+                self.tail = self.head;
+
+                let (a, b) = self.physical_slices(start, count);
+                $crate::Drain::new(a, b)
+            }
+
+            pub fn nth_oldest_mut(&mut self, n : usize) -> Option<&mut $type> {
+                if n < $crate::RingLen::len(self) {
+                    let slot = self.index(n);
+                    Some(&mut self.buffer[slot])
+                } else {
+                    None
+                }
+            }
+
+            pub fn nth_newest_mut(&mut self, n : usize) -> Option<&mut $type> {
+                let len = $crate::RingLen::len(self);
+                if n < len {
+                    let slot = self.index(len - 1 - n);
+                    Some(&mut self.buffer[slot])
+                } else {
+                    None
+                }
+            }
+
+            pub fn push_if_space(&mut self) -> Option<&mut $type> {
+                if self.is_full() {
+                    None
+                } else {
+                    let slot = self.index($crate::RingLen::len(self));
+                    $(self.$written = self.$written.wrapping_add(1);)?
+                    self.push_head();
+                    Some(&mut self.buffer[slot])
+                }
+            }
+
+            pub fn slide(&mut self, items : &[$type]) {
+                let capacity = Self::capacity();
+                if capacity == 0 {
+                    // A size of 1 only has the reserved slot.
+                    return;
+                }
+
+                if items.len() < capacity {
+                    for &item in items {
+                        self.push(item);
+                    }
+                } else {
+                    // Full with the oldest element right after the reserved slot.
+                    self.buffer[1..].copy_from_slice(&items[items.len() - capacity..]);
+                    self.tail = 1;
+                    self.head = 0;
+                    $(self.$written = self.$written.wrapping_add(items.len());)?
+                }
+            }
+
+            pub fn from_bytes(bytes : &[u8; $size + 8]) -> Option<$name> where for<'a> $type : From<u8> {
+                let tail = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize;
+                let head = u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]) as usize;
+                if tail >= Self::SIZE || head >= Self::SIZE {
+                    return None;
+                }
+
+                let mut rb = $name::new();
+                rb.tail = tail as _;
+                rb.head = head as _;
+                for (item, &byte) in rb.buffer.iter_mut().zip(bytes[8..].iter()) {
+                    *item = <$type as From<u8>>::from(byte);
+                }
+                Some(rb)
+            }
+
+            pub fn split_first(&mut self) -> Option<(&$type, usize)> {
+                if $crate::RingLen::is_empty(self) {
+                    None
+                } else {
+                    let slot = self.index(0);
+                    self.push_tail();
+                    Some((&self.buffer[slot], $crate::RingLen::len(self)))
+                }
+            }
+
+            #[inline(always)]
+            pub fn shrink_len_to_newest(&mut self, n : usize) {
+                let len = $crate::RingLen::len(self);
+                if len > n {
+                    self.tail = self.index(len - n) as _;
+                }
+            }
+
+            pub fn replace(&mut self, index : usize, value : $type) -> Option<$type> {
+                if index < $crate::RingLen::len(self) {
+                    let slot = self.index(index);
+                    Some(::core::mem::replace(&mut self.buffer[slot], value))
+                } else {
+                    None
+                }
+            }
+        }
+    };
+}
+
+/// Methods removing or inserting elements elsewhere than the oldest end, thus moving `head` back or shifting elements.
+/// 
+/// Not included by the broadcast arm, since readers count elements from `head` and would skip or read again elements.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! ring_core_splice {
+    ($name : ident[$type : ty; $size : expr]) => {
+        #[allow(dead_code)]
+        impl $name {
+            pub fn split_last(&mut self) -> Option<(&$type, usize)> {
+                let len = $crate::RingLen::len(self);
+                if len == 0 {
+                    None
+                } else {
+                    let slot = self.index(len - 1);
+                    self.pop_head();
+                    Some((&self.buffer[slot], len - 1))
+                }
+            }
+
+            #[inline(always)]
+            pub fn pop_back(&mut self) -> Option<$type> {
+                self.split_last().map(|(&item, _)| item)
+            }
+
+            pub fn clone_from_ring(&mut self, src : &Self) {
+                let len = $crate::RingLen::len(src);
+                for i in 0..len {
+                    self.buffer[i] = src.buffer[src.index(i)];
+                }
+                self.tail = 0;
+                self.head = len as _;
+            }
+
+            pub fn dedup_by<F : FnMut(&$type, &$type) -> bool>(&mut self, mut same : F) {
+                let len = $crate::RingLen::len(self);
+                let mut kept = len.min(1);
+                for i in 1..len {
+                    let item = self.buffer[self.index(i)];
+                    if !same(&item, &self.buffer[self.index(kept - 1)]) {
+                        self.buffer[self.index(kept)] = item;
+                        kept += 1;
+                    }
+                }
+                for _ in kept..len {
+                    self.pop_head();
+                }
+            }
+
+            pub fn split_off(&mut self, index : usize) -> Self {
+                let len = $crate::RingLen::len(self);
+                assert!(index <= len);
+
+                let mut other = Self::new();
+                for i in index..len {
+                    other.push(self.buffer[self.index(i)]);
+                }
+                for _ in index..len {
+                    self.pop_head();
+                }
+                other
+            }
+
+            pub fn swap_remove(&mut self, index : usize) -> Option<$type> {
+                let len = $crate::RingLen::len(self);
+                if index >= len {
+                    None
+                } else {
+                    let slot = self.index(index);
+                    let item = self.buffer[slot];
+                    self.buffer[slot] = self.buffer[self.index(len - 1)];
+                    self.pop_head();
+                    Some(item)
+                }
+            }
+
+            pub fn remove(&mut self, index : usize) -> Option<$type> {
+                let len = $crate::RingLen::len(self);
+                if index >= len {
+                    None
+                } else {
+                    let item = self.buffer[self.index(index)];
+                    for i in index..len - 1 {
+                        self.buffer[self.index(i)] = self.buffer[self.index(i + 1)];
+                    }
+                    self.pop_head();
+                    Some(item)
+                }
+            }
+
+            pub fn insert(&mut self, index : usize, item : $type) -> Result<(), $type> {
+                let len = $crate::RingLen::len(self);
+                assert!(index <= len);
+
+                if self.is_full() {
+                    Err(item)
+                } else {
+                    self.push_head();
+                    for i in (index..len).rev() {
+                        self.buffer[self.index(i + 1)] = self.buffer[self.index(i)];
+                    }
+                    self.buffer[self.index(index)] = item;
+                    Ok(())
+                }
+            }
+        }
+    };
+}
+
+/// Compile time check of the backing array of unchecked buffers against [NSRB_STACK_LIMIT](super::NSRB_STACK_LIMIT),
+/// removed with the `no_limit` feature of nsrb rather than of the calling crate.
+#[cfg(not(feature = "no_limit"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! ring_stack_limit {
+    ($int : ty, $type : ty) => {
+        // Oversized buffers are rejected by try_new() instead.
+        const _ : () = assert!(<$int>::MAX as usize > $crate::NSRB_UPPER_LIMIT ||
+            ::core::mem::size_of::<[$type; <$int>::MAX as usize + 1]>() <= $crate::NSRB_STACK_LIMIT,
+            "Unchecked buffer is larger than NSRB_STACK_LIMIT bytes and would overflow the stack.");
+    };
+}
+
+#[cfg(feature = "no_limit")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! ring_stack_limit {
+    ($int : ty, $type : ty) => {};
+}
+
+/// Methods returning floating point values, only generated with the `float` feature.
+#[cfg(feature = "float")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! ring_float {
+    ($name : ident) => {
+        #[allow(dead_code)]
+        impl $name {
+            pub fn occupancy(&self) -> f32 {
+                if Self::capacity() == 0 {
+                    0.0
+                } else {
+                    $crate::RingLen::len(self) as f32 / Self::capacity() as f32
+                }
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "float"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! ring_float {
+    ($name : ident) => {};
+}
+
+/// Implementation of [arbitrary::Arbitrary], only generated with the `arbitrary` feature.
+#[cfg(feature = "arbitrary")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! ring_arbitrary {
+    ($name : ident[$type : ty; $size : expr]) => {
+        impl<'a> $crate::arbitrary::Arbitrary<'a> for $name where $type : $crate::arbitrary::Arbitrary<'a> {
+            fn arbitrary(u : &mut $crate::arbitrary::Unstructured<'a>) -> $crate::arbitrary::Result<Self> {
+                let mut rb = $name::new();
+
+                // Move cursors to an arbitrary position so wrapping is covered.
+                for _ in 0..u.int_in_range(0..=Self::capacity())? {
+                    rb.push(<$type>::default());
+                    rb.pop();
+                }
+
+                for _ in 0..u.int_in_range(0..=Self::capacity())? {
+                    rb.push(<$type>::arbitrary(u)?);
+                }
+
+                Ok(rb)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "arbitrary"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! ring_arbitrary {
+    ($name : ident[$type : ty; $size : expr]) => {};
+}
+
+/// Implementation of [zeroize::Zeroize], only generated with the `zeroize` feature.
+#[cfg(feature = "zeroize")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! ring_zeroize {
+    ($name : ident[$type : ty]) => {
+        impl $crate::zeroize::Zeroize for $name where for<'a> $type : $crate::zeroize::Zeroize {
+            fn zeroize(&mut self) {
+                // Reset cursors and extra state first since new() overwrites the buffer without volatile writes.
+                *self = Self::new();
+                for item in self.buffer.iter_mut() {
+                    <$type as $crate::zeroize::Zeroize>::zeroize(item);
+                }
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "zeroize"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! ring_zeroize {
+    ($name : ident[$type : ty]) => {};
+}
+
+/// Implementation shared by every ring buffer arm.
+/// 
+/// Only rely on the `try_new`, `push`, `pop`, `len`, `index` and `push_head` methods generated by each arm.
+/// 
+/// Trait bounds on `$type` are written `for<'a> $type : Trait` so they are only checked when the
+/// method is called, letting `$type` omit those traits.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! ring_core {
+    ($name : ident[$type : ty; $size : expr]) => {
+        #[allow(dead_code)]
+        impl $name {
+            pub const SIZE : usize = $size;
+
+            pub fn new() -> $name {
+                Self::try_new().unwrap()
+            }
+
+            pub fn with_capacity_checked(n : usize) -> Result<$name, $crate::RingError> {
+                if n > Self::capacity() {
+                    return Err($crate::RingError::CapacityExceeded);
+                }
+                Self::try_new()
+            }
+
+            #[inline(always)]
+            pub const fn capacity_bytes() -> usize {
+                ::core::mem::size_of::<Self>()
+            }
+
+            #[inline(always)]
+            pub fn is_full(&self) -> bool {
+                $crate::RingLen::len(self) == Self::capacity()
+            }
+
+            #[inline(always)]
+            pub fn remaining_bytes(&self) -> usize where for<'a> [$type] : AsRef<[u8]> {
+                Self::capacity() - $crate::RingLen::len(self)
+            }
+
+            pub fn assert_invariants(&self) {
+                debug_assert!((self.tail as usize) < Self::SIZE, "Ring buffer tail {} is out of the backing array.", self.tail);
+                debug_assert!((self.head as usize) < Self::SIZE, "Ring buffer head {} is out of the backing array.", self.head);
+                debug_assert!($crate::RingLen::len(self) <= Self::capacity(), "Ring buffer length {} is larger than its capacity.", $crate::RingLen::len(self));
+
+                #[cfg(debug_assertions)]
+                {
+                    // Count the slots between the cursors without the length formula.
+                    let mut slot = self.tail as usize;
+                    let mut count = 0;
+                    while slot != self.head as usize && count < Self::SIZE {
+                        slot = if slot + 1 == Self::SIZE { 0 } else { slot + 1 };
+                        count += 1;
+                    }
+                    assert_eq!(count, $crate::RingLen::len(self), "Ring buffer cursors don't match its length.");
+                }
+            }
+
+            pub fn occupancy_permille(&self) -> u16 {
+                if Self::capacity() == 0 {
+                    0
+                } else {
+                    ($crate::RingLen::len(self) as u64 * 1000 / Self::capacity() as u64) as u16
+                }
+            }
+
+            pub fn reset(&mut self) {
+                *self = Self::new();
+            }
+
+            #[inline(always)]
+            pub fn shrink_to_fit(&mut self) {}
+
+            #[inline(always)]
+            pub fn push_reporting(&mut self, item : $type) -> bool {
+                let full = self.is_full();
+                self.push(item);
+                full
+            }
+
+            #[inline(always)]
+            pub fn shift_in(&mut self, item : $type) -> Option<$type> {
+                let shifted = if self.is_full() { Some(self.buffer[self.index(0)]) } else { None };
+                self.push(item);
+                shifted
+            }
+
+            pub fn push_if_changed(&mut self, item : $type) -> bool where for<'a> $type : PartialEq {
+                match self.tap(0) {
+                    Some(newest) if <$type as PartialEq>::eq(newest, &item) => false,
+                    _ => {
+                        self.push(item);
+                        true
+                    }
+                }
+            }
+
+            #[inline(always)]
+            pub fn emplace<F : FnOnce() -> $type>(&mut self, make : F) {
+                self.push(make());
+            }
+
+            #[inline(always)]
+            pub fn try_emplace<F : FnOnce() -> $type>(&mut self, make : F) -> bool {
+                if self.is_full() {
+                    false
+                } else {
+                    self.push(make());
+                    true
+                }
+            }
+
+            pub fn fill_from_fn<F : FnMut() -> $type>(&mut self, mut make : F) {
+                while !self.is_full() {
+                    self.push(make());
+                }
+            }
+
+            pub fn clear_and_fill(&mut self, item : $type) {
+                self.reset();
+                self.fill_from_fn(|| item);
+            }
+
+            pub fn extend_counting<I : ::core::iter::IntoIterator<Item = $type>>(&mut self, iter : I) -> usize {
+                let mut dropped = 0;
+                for item in iter {
+                    if self.is_full() {
+                        dropped += 1;
+                    }
+                    self.push(item);
+                }
+                dropped
+            }
+
+            pub fn push_iter_bounded<I : ::core::iter::IntoIterator<Item = $type>>(&mut self, iter : I, max : usize) -> usize {
+                let mut pushed = 0;
+                for item in iter.into_iter().take(max) {
+                    self.push(item);
+                    pushed += 1;
+                }
+                pushed
+            }
+
+            pub fn push_with_headroom(&mut self, item : $type, headroom : usize) -> Result<(), $type> {
+                if $crate::RingLen::len(self).saturating_add(headroom) >= Self::capacity() {
+                    Err(item)
+                } else {
+                    self.push(item);
+                    Ok(())
+                }
+            }
+
+            #[allow(clippy::result_unit_err)]
+            pub fn push_all_or_none(&mut self, items : &[$type]) -> Result<(), ()> {
+                if items.len() > Self::capacity() - $crate::RingLen::len(self) {
+                    Err(())
+                } else {
+                    for &item in items {
+                        self.push(item);
+                    }
+                    Ok(())
+                }
+            }
+
+            pub fn retain_newest(&mut self, n : usize) {
+                while $crate::RingLen::len(self) > n {
+                    self.pop();
+                }
+            }
+
+            pub fn drain_to_slice_exact(&mut self, out : &mut [$type]) -> bool {
+                if out.len() > $crate::RingLen::len(self) {
+                    false
+                } else {
+                    for slot in out.iter_mut() {
+                        *slot = self.buffer[self.index(0)];
+                        self.pop();
+                    }
+                    true
+                }
+            }
+
+            pub fn drain_into(&mut self, other : &mut Self) -> usize {
+                let count = $crate::RingLen::len(self).min(Self::capacity() - $crate::RingLen::len(other));
+                for _ in 0..count {
+                    other.push(self.buffer[self.index(0)]);
+                    self.pop();
+                }
+                count
+            }
+
+            pub fn collect_into(&mut self, out : &mut [$type]) -> usize {
+                let count = $crate::RingLen::len(self).min(out.len());
+                self.drain_to_slice_exact(&mut out[..count]);
+                count
+            }
+
+            pub fn peek_while<F : FnMut(&$type) -> bool>(&self, mut pred : F) -> usize {
+                self.iter().take_while(|item| pred(item)).count()
+            }
+
+            pub fn pop_while<F : FnMut(&$type) -> bool>(&mut self, mut pred : F) -> usize {
+                let mut popped = 0;
+                while !$crate::RingLen::is_empty(self) && pred(&self.buffer[self.index(0)]) {
+                    self.pop();
+                    popped += 1;
+                }
+                popped
+            }
+
+            pub fn position(&self, item : &$type) -> Option<usize> where for<'a> $type : PartialEq {
+                (0..$crate::RingLen::len(self)).find(|&i| self.buffer[self.index(i)] == *item)
+            }
+
+            #[inline(always)]
+            pub fn find_byte(&self, byte : $type) -> Option<usize> where for<'a> $type : PartialEq {
+                self.position(&byte)
+            }
+
+            pub fn rposition(&self, item : &$type) -> Option<usize> where for<'a> $type : PartialEq {
+                (0..$crate::RingLen::len(self)).rev().find(|&i| self.buffer[self.index(i)] == *item)
+            }
+
+            pub fn histogram<const B : usize>(&self) -> [usize; B] where for<'a> usize : ::core::convert::TryFrom<$type> {
+                let mut buckets = [0; B];
+                if let Some(last) = B.checked_sub(1) {
+                    for &item in self.iter() {
+                        let bucket = <usize as ::core::convert::TryFrom<$type>>::try_from(item).map_or(last, |x| x.min(last));
+                        buckets[bucket] += 1;
+                    }
+                }
+                buckets
+            }
+
+            pub fn fold<B, F : FnMut(B, &$type) -> B>(&self, init : B, f : F) -> B {
+                self.iter().fold(init, f)
+            }
+
+            pub fn eq_slice_by<F : FnMut(&$type, &$type) -> bool>(&self, other : &[$type], mut eq : F) -> bool {
+                $crate::RingLen::len(self) == other.len() && self.iter().zip(other.iter()).all(|(a, b)| eq(a, b))
+            }
+
+            pub fn contains_by<F : FnMut(&$type, &$type) -> bool>(&self, item : &$type, mut eq : F) -> bool {
+                self.iter().any(|other| eq(other, item))
+            }
+
+            pub fn last_distinct(&self) -> Option<&$type> where for<'a> $type : PartialEq {
+                (1..$crate::RingLen::len(self)).rev()
+                    .find(|&i| self.buffer[self.index(i)] != self.buffer[self.index(i - 1)])
+                    .map(|i| &self.buffer[self.index(i)])
+            }
+
+            pub fn contains_subsequence(&self, pattern : &[$type]) -> bool where for<'a> $type : PartialEq {
+                let len = $crate::RingLen::len(self);
+                pattern.len() <= len && (0..=len - pattern.len()).any(|start| {
+                    pattern.iter().enumerate().all(|(i, item)| self.buffer[self.index(start + i)] == *item)
+                })
+            }
+
+            pub fn min_by_key<K : Ord, F : FnMut(&$type) -> K>(&self, mut f : F) -> Option<&$type> {
+                self.iter().min_by_key(|item| f(item))
+            }
+
+            pub fn max_by_key<K : Ord, F : FnMut(&$type) -> K>(&self, mut f : F) -> Option<&$type> {
+                self.iter().max_by_key(|item| f(item))
+            }
+
+            pub fn is_sorted(&self) -> bool where for<'a> $type : PartialOrd {
+                (1..$crate::RingLen::len(self)).all(|i| self.buffer[self.index(i - 1)] <= self.buffer[self.index(i)])
+            }
+
+            pub fn binary_search(&self, item : &$type) -> Result<usize, usize> where for<'a> $type : Ord {
+                let mut low = 0;
+                let mut high = $crate::RingLen::len(self);
+
+                while low < high {
+                    let mid = low + (high - low) / 2;
+                    match self.buffer[self.index(mid)].cmp(item) {
+                        ::core::cmp::Ordering::Less => low = mid + 1,
+                        ::core::cmp::Ordering::Greater => high = mid,
+                        ::core::cmp::Ordering::Equal => return Ok(mid),
+                    }
+                }
+                Err(low)
+            }
+
+            #[inline(always)]
+            pub fn oldest(&self) -> Option<&$type> {
+                if $crate::RingLen::is_empty(self) {
+                    None
+                } else {
+                    Some(&self.buffer[self.index(0)])
+                }
+            }
+
+            #[inline(always)]
+            pub fn newest(&self) -> Option<&$type> {
+                self.tap(0)
+            }
+
+            #[inline(always)]
+            pub fn front(&self) -> Option<&$type> {
+                self.oldest()
+            }
+
+            #[inline(always)]
+            pub fn back(&self) -> Option<&$type> {
+                self.newest()
+            }
+
+            #[inline(always)]
+            pub fn pop_front(&mut self) -> Option<$type> {
+                let item = self.oldest().copied();
+                self.pop();
+                item
+            }
+
+            pub fn pop_with<F : FnOnce($type)>(&mut self, f : F) -> bool {
+                match self.pop_front() {
+                    Some(item) => {
+                        f(item);
+                        true
+                    },
+                    None => false,
+                }
+            }
+
+            pub fn push_spin<F : FnMut(&mut Self)>(&mut self, item : $type, mut backoff : F) {
+                while self.is_full() {
+                    backoff(self);
+                    ::core::hint::spin_loop();
+                }
+                self.push(item);
+            }
+
+            pub fn pop_spin<F : FnMut(&mut Self)>(&mut self, mut backoff : F) -> $type {
+                loop {
+                    if let Some(item) = self.pop_front() {
+                        return item;
+                    }
+                    backoff(self);
+                    ::core::hint::spin_loop();
+                }
+            }
+
+            #[inline(always)]
+            pub fn tap(&self, n : usize) -> Option<&$type> {
+                let len = $crate::RingLen::len(self);
+                if n < len {
+                    Some(&self.buffer[self.index(len - 1 - n)])
+                } else {
+                    None
+                }
+            }
+
+            pub fn iter(&self) -> $crate::Iter<'_, $type> {
+                let (a, b) = self.slices(0, $crate::RingLen::len(self));
+                $crate::Iter::new(a, b)
+            }
+
+            pub fn peek_slice(&self, n : usize) -> Option<&[$type]> {
+                if n > $crate::RingLen::len(self) {
+                    None
+                } else {
+                    match self.slices(0, n) {
+                        (a, []) => Some(a),
+                        _ => None,
+                    }
+                }
+            }
+
+            pub fn as_slice_from(&self, consumed : usize) -> (&[$type], &[$type]) {
+                let len = $crate::RingLen::len(self);
+                self.slices(consumed.min(len), len)
+            }
+
+            pub fn try_peek_array<const M : usize>(&self) -> Option<[$type; M]> {
+                if M > $crate::RingLen::len(self) {
+                    None
+                } else {
+                    Some(::core::array::from_fn(|i| self.buffer[self.index(i)]))
+                }
+            }
+
+            pub fn items(&self, out : &mut [$type]) -> usize {
+                let count = $crate::RingLen::len(self).min(out.len());
+                let (a, b) = self.slices(0, count);
+                out[..a.len()].copy_from_slice(a);
+                out[a.len()..count].copy_from_slice(b);
+                count
+            }
+
+            pub fn map_into<U, R : ::core::iter::FromIterator<U>, F : FnMut(&$type) -> U>(&self, f : F) -> R {
+                self.iter().map(f).collect()
+            }
+
+            pub fn flat_map_into<U, I, R, F>(&self, f : F) -> R
+                where I : ::core::iter::IntoIterator<Item = U>, R : ::core::iter::FromIterator<U>, F : FnMut(&$type) -> I {
+                self.iter().flat_map(f).collect()
+            }
+
+            pub fn into_ring<R : ::core::iter::FromIterator<$type>>(self) -> R {
+                self.into_iter().collect()
+            }
+
+            pub fn zip_with<F : FnMut(&$type, &$type) -> $type>(&self, other : &Self, mut f : F) -> Self {
+                self.iter().zip(other.iter()).map(|(a, b)| f(a, b)).collect()
+            }
+
+            pub fn merge_sorted(&self, other : &Self) -> Self where for<'a> $type : Ord {
+                let mut rb = Self::new();
+                let (mut a, mut b) = (self.iter().peekable(), other.iter().peekable());
+                while !rb.is_full() {
+                    let item = match (a.peek(), b.peek()) {
+                        (Some(&x), Some(&y)) => if y < x { b.next() } else { a.next() },
+                        (Some(_), None) => a.next(),
+                        (None, Some(_)) => b.next(),
+                        (None, None) => break,
+                    };
+                    if let Some(&item) = item {
+                        rb.push(item);
+                    }
+                }
+                rb
+            }
+
+            pub fn interleave(&self, other : &Self) -> Self {
+                let mut rb = Self::new();
+                let (mut a, mut b) = (self.iter(), other.iter());
+                loop {
+                    let (x, y) = (a.next(), b.next());
+                    if x.is_none() && y.is_none() {
+                        break;
+                    }
+                    if let Some(&item) = x {
+                        rb.push(item);
+                    }
+                    if let Some(&item) = y {
+                        rb.push(item);
+                    }
+                }
+                rb
+            }
+
+            pub fn split_at(&self, index : usize) -> (impl Iterator<Item = &$type>, impl Iterator<Item = &$type>) {
+                assert!(index <= $crate::RingLen::len(self));
+
+                let (a, b) = self.slices(0, index);
+                let (c, d) = self.slices(index, $crate::RingLen::len(self));
+                (a.iter().chain(b.iter()), c.iter().chain(d.iter()))
+            }
+
+            pub fn hex(&self) -> $crate::HexDisplay<'_> where for<'a> [$type] : AsRef<[u8]> {
+                let (a, b) = self.slices(0, $crate::RingLen::len(self));
+                $crate::HexDisplay(<[$type] as AsRef<[u8]>>::as_ref(a), <[$type] as AsRef<[u8]>>::as_ref(b))
+            }
+
+            #[inline(always)]
+            pub fn as_ptr(&self) -> *const $type {
+                self.buffer.as_ptr()
+            }
+
+            #[inline(always)]
+            pub fn as_mut_ptr(&mut self) -> *mut $type {
+                self.buffer.as_mut_ptr()
+            }
+
+            #[inline(always)]
+            pub fn physical_head(&self) -> usize {
+                self.index($crate::RingLen::len(self))
+            }
+
+            #[inline(always)]
+            pub fn physical_tail(&self) -> usize {
+                self.index(0)
+            }
+
+            pub fn as_bytes(&self) -> [u8; $size + 8] where for<'a> u8 : From<$type> {
+                let tail = u32::try_from(self.tail).expect("Ring buffer tail doesn't fit in u32.").to_le_bytes();
+                let head = u32::try_from(self.head).expect("Ring buffer head doesn't fit in u32.").to_le_bytes();
+                ::core::array::from_fn(|i| match i {
+                    0..=3 => tail[i],
+                    4..=7 => head[i - 4],
+                    _ => <u8 as From<$type>>::from(self.buffer[i - 8]),
+                })
+            }
+
+            pub fn into_array(mut self) -> ([$type; $size], usize) {
+                let len = $crate::RingLen::len(&self);
+                let tail = self.index(0);
+                self.buffer.rotate_left(tail);
+                (self.buffer, len)
+            }
+
+            /// Returns the elements between logical `start` and `end` as 2 slices since they may wrap.
+            #[inline(always)]
+            fn slices(&self, start : usize, end : usize) -> (&[$type], &[$type]) {
+                self.physical_slices(self.index(start), end - start)
+            }
+
+            /// Returns `count` elements from physical index `start` as 2 slices since they may wrap.
+            fn physical_slices(&self, start : usize, count : usize) -> (&[$type], &[$type]) {
+                if start + count <= self.buffer.len() {
+                    (&self.buffer[start..start + count], &[])
+                } else {
+                    (&self.buffer[start..], &self.buffer[..start + count - self.buffer.len()])
+                }
+            }
+        }
+
+        $crate::ring_float!($name);
+        $crate::ring_arbitrary!($name[$type; $size]);
+        $crate::ring_zeroize!($name[$type]);
+
+        impl ::core::iter::Extend<$type> for $name {
+            fn extend<I: ::core::iter::IntoIterator<Item = $type>>(&mut self, iter: I) {
+                for item in iter {
+                    self.push(item);
+                }
+            }
+        }
+
+        impl ::core::ops::Index<usize> for $name {
+            type Output = $type;
+
+            fn index(&self, index : usize) -> &$type {
+                assert!(index < $crate::RingLen::len(self), "Index {} is out of a ring buffer of length {}.", index, $crate::RingLen::len(self));
+                &self.buffer[$name::index(self, index)]
+            }
+        }
+
+        impl ::core::iter::IntoIterator for $name {
+            type Item = $type;
+            type IntoIter = ::core::iter::Take<::core::array::IntoIter<$type, { $size }>>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                let (array, len) = self.into_array();
+                array.into_iter().take(len)
+            }
+        }
+
+        impl<'a> ::core::iter::IntoIterator for &'a $name {
+            type Item = &'a $type;
+            type IntoIter = $crate::Iter<'a, $type>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        impl ::core::iter::FromIterator<$type> for $name {
+            fn from_iter<I: ::core::iter::IntoIterator<Item = $type>>(iter: I) -> Self {
+                let mut rb = $name::new();
+                rb.extend(iter);
+                rb
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+pub(crate) mod tests_checked {
+    use crate::{RingLen, RingCursors};
+
+    // Test the lower limit of ring buffer
+    #[cfg(not(feature = "no_limit"))]
+    ring!(TooSmall[usize;super::super::NSRB_LOWER_LIMIT - 1]);
+    #[test]
+    #[cfg(not(feature = "no_limit"))]
+    #[should_panic]
+    fn ring_lower_limit() {
+        let _ = TooSmall::new();
+    }
+
+    // Test the upper limit of ring buffer
+    #[cfg(not(feature = "no_limit"))]
+    ring!(TooBig[usize;super::super::NSRB_UPPER_LIMIT + 1]);
+    #[test]
+    #[cfg(not(feature = "no_limit"))]
+    #[should_panic]
+    fn ring_upper_limit() {
+        let _ = TooBig::new();
+    }
+
+    // Test limits without panicking
+    #[test]
+    fn ring_try_new() {
+        #[cfg(not(feature = "no_limit"))]
+        assert_eq!(TooSmall::try_new().err(), Some(crate::RingError::TooSmall));
+        #[cfg(not(feature = "no_limit"))]
+        assert_eq!(TooBig::try_new().err(), Some(crate::RingError::TooLarge));
+        assert!(RbPP::try_new().is_ok());
+    }
+
+    // Test checking a runtime capacity
+    #[test]
+    fn ring_with_capacity_checked() {
+        assert!(RbPP::with_capacity_checked(0).is_ok());
+        assert!(RbPP::with_capacity_checked(9).is_ok());
+        assert_eq!(RbPP::with_capacity_checked(10).err(), Some(crate::RingError::CapacityExceeded));
+        #[cfg(not(feature = "no_limit"))]
+        assert_eq!(TooBig::with_capacity_checked(1).err(), Some(crate::RingError::TooLarge));
+    }
+
+    // Test push and pop of ring buffer
+    ring!(RbPP[usize;10]);
+    #[test]
+    fn ring_push_pop() {
+        let mut rb = RbPP::new();
+
+        for i in 0..15 {
+            rb.push(i);
+        }
+
+        for i in 6..15 {
+            assert_eq!(*rb.pop().unwrap(), i);
+        }
+
+        assert!(rb.pop().is_none());
+    }
+
+    // Test capacity in const context
+    ring!(RbCap[usize;10]);
+    const RB_CAP_ARRAY : [usize; RbCap::capacity()] = [0; RbCap::capacity()];
+    const RB_SIZE_ARRAY : [usize; RbCap::SIZE] = [0; RbCap::SIZE];
+    #[test]
+    fn ring_capacity() {
+        assert_eq!(RB_CAP_ARRAY.len(), 9);
+        assert_eq!(RB_SIZE_ARRAY.len(), 10);
+
+        let mut rb = RbCap::new();
+        for i in 0..RbCap::capacity() {
+            rb.push(i);
+        }
+
+        for i in 0..RB_CAP_ARRAY.len() {
+            assert_eq!(*rb.pop().unwrap(), i);
+        }
+
+        assert!(rb.pop().is_none());
+    }
+
+    // Test fill ratio
+    ring!(RbOccupancy[usize;11]);
+    #[test]
+    fn ring_occupancy() {
+        let mut rb = RbOccupancy::new();
+        assert_eq!(rb.occupancy_permille(), 0);
+
+        rb.extend(0..5);
+        assert_eq!(rb.occupancy_permille(), 500);
+
+        rb.extend(0..5);
+        assert_eq!(rb.occupancy_permille(), 1000);
+    }
+
+    #[test]
+    #[cfg(feature = "float")]
+    fn ring_occupancy_float() {
+        let mut rb = RbOccupancy::new();
+        assert_eq!(rb.occupancy(), 0.0);
+
+        rb.extend(0..5);
+        assert_eq!(rb.occupancy(), 0.5);
+
+        rb.extend(0..5);
+        assert_eq!(rb.occupancy(), 1.0);
+    }
+
+    // Test invariants catch corrupted cursors
+    ring!(RbInvariants[usize;6]);
+    #[test]
+    fn ring_assert_invariants() {
+        let mut rb = RbInvariants::new();
+        rb.assert_invariants();
+        rb.extend(0..9);
+        rb.pop();
+        rb.assert_invariants();
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic]
+    fn ring_assert_invariants_corrupted() {
+        let mut rb = RbInvariants::new();
+        rb.extend(0..3);
+        rb.head = 6;
+        rb.assert_invariants();
+    }
+
+    // Test remaining bytes of a stream buffer
+    ring!(RbStream[u8;16]);
+    #[test]
+    fn ring_remaining_bytes() {
+        let mut rb = RbStream::new();
+        assert_eq!(rb.remaining_bytes(), 15);
+
+        rb.extend(*b"hello");
+        assert_eq!(rb.remaining_bytes(), 10);
+
+        rb.pop();
+        rb.pop();
+        assert_eq!(rb.remaining_bytes(), 12);
+
+        rb.extend(*b"hello world!");
+        assert!(rb.is_full());
+        assert_eq!(rb.remaining_bytes(), 0);
+    }
+
+    // Test memory size
+    ring!(RbBytes[u32;10]);
+    const RB_BYTES : usize = RbBytes::capacity_bytes();
+    #[test]
+    fn ring_capacity_bytes() {
+        assert_eq!(RB_BYTES, 10 * 4 + 2 * core::mem::size_of::<usize>());
+    }
+
+    // Test creating arbitrary states from raw parts
+    ring!(RbRaw7[usize;7]);
+    #[test]
+    fn ring_from_raw_parts() {
+        let rb = unsafe { RbRaw7::from_raw_parts([4, 5, 0, 0, 1, 2, 3], 2, 4) };
+        assert_eq!(rb.len(), 5);
+        assert!(rb.iter().eq([1, 2, 3, 4, 5].iter()));
+
+        let rb = unsafe { RbRaw7::from_raw_parts([0, 1, 2, 3, 4, 5, 6], 6, 0) };
+        assert!(rb.is_full());
+        assert!(rb.iter().eq([0, 1, 2, 3, 4, 5].iter()));
+
+        let rb = unsafe { RbRaw7::from_raw_parts([9; 7], 3, 3) };
+        assert!(rb.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn ring_from_raw_parts_out_of_range() {
+        let _ = unsafe { RbRaw7::from_raw_parts([0; 7], 7, 0) };
+    }
+
+    // Test extend overwriting the oldest elements
+    ring!(RbExtend[usize;10]);
+    #[test]
+    fn ring_extend() {
+        let mut rb = RbExtend::new();
+
+        rb.extend([1, 2, 3]);
+        for i in 1..4 {
+            assert_eq!(*rb.pop().unwrap(), i);
+        }
+        assert!(rb.pop().is_none());
+
+        rb.extend(0..20);
+        for i in 11..20 {
+            assert_eq!(*rb.pop().unwrap(), i);
+        }
+        assert!(rb.pop().is_none());
+    }
+
+    // Test counting overwritten elements
+    #[test]
+    fn ring_extend_counting() {
+        let mut rb = RbExtend::new();
+
+        assert_eq!(rb.extend_counting(0..5), 0);
+        assert_eq!(rb.extend_counting(5..9), 0);
+        assert_eq!(rb.extend_counting(9..15), 6);
+
+        for i in 6..15 {
+            assert_eq!(*rb.pop().unwrap(), i);
+        }
+    }
+
+    // Test bounded push from an iterator
+    ring!(RbBounded[usize;4]);
+    #[test]
+    fn ring_push_iter_bounded() {
+        let mut rb = RbBounded::new();
+        let mut source = 0..100;
+
+        assert_eq!(rb.push_iter_bounded(&mut source, 2), 2);
+        assert!(rb.iter().eq([0, 1].iter()));
+        assert_eq!(source.next(), Some(2));
+
+        assert_eq!(rb.push_iter_bounded(&mut source, 0), 0);
+        assert_eq!(rb.push_iter_bounded(3..5, 10), 2);
+        assert!(rb.iter().eq([1, 3, 4].iter()));
+    }
+
+    // Test keeping free slots for bursts
+    ring!(RbJitter[u16;10]);
+    #[test]
+    fn ring_push_with_headroom() {
+        let mut rb = RbJitter::new();
+        let headroom = 3;
+
+        for packet in 0..6 {
+            assert_eq!(rb.push_with_headroom(packet, headroom), Ok(()));
+        }
+        assert_eq!(rb.push_with_headroom(6, headroom), Err(6));
+        assert_eq!(rb.len(), RbJitter::capacity() - headroom);
+
+        // Space is still there for a burst
+        rb.extend([6, 7, 8]);
+        assert!(rb.is_full());
+        assert_eq!(rb.push_with_headroom(9, 0), Err(9));
+        assert_eq!(rb.push_with_headroom(9, usize::MAX), Err(9));
+        assert!(rb.iter().copied().eq(0..9));
+    }
+
+    // Test transactional bulk push
+    #[test]
+    fn ring_push_all_or_none() {
+        let mut rb = RbExtend::new();
+        rb.extend(0..5);
+
+        assert_eq!(rb.push_all_or_none(&[5, 6, 7, 8, 9]), Err(()));
+        assert_eq!(rb.len(), 5);
+
+        assert_eq!(rb.push_all_or_none(&[5, 6, 7, 8]), Ok(()));
+        assert!(rb.is_full());
+        assert!(rb.iter().eq((0..9).collect::<RbExtend>().iter()));
+
+        assert_eq!(rb.push_all_or_none(&[]), Ok(()));
+        assert_eq!(rb.push_all_or_none(&[9]), Err(()));
+        assert_eq!(*rb.pop().unwrap(), 0);
+    }
+
+    // Test collect into a ring buffer
+    ring!(RbCollect[usize;16]);
+    #[test]
+    fn ring_from_iter() {
+        let mut rb : RbCollect = (0..40).collect();
+
+        for i in 25..40 {
+            assert_eq!(*rb.pop().unwrap(), i);
+        }
+        assert!(rb.pop().is_none());
+    }
+
+    // Test keeping the newest elements
+    ring!(RbRetain[usize;8]);
+    #[test]
+    fn ring_retain_newest() {
+        let mut rb = RbRetain::new();
+        rb.extend(0..12);
+        assert!(rb.is_full());
+
+        rb.retain_newest(3);
+        assert!(rb.iter().eq([9, 10, 11].iter()));
+
+        rb.retain_newest(5);
+        assert_eq!(rb.len(), 3);
+
+        rb.retain_newest(0);
+        assert!(rb.is_empty());
+    }
+
+    // Test keeping the newest elements without moving them
+    ring!(RbShrink[u32;6]);
+    ring!(@unchecked(u8) RbShrinkUnchecked[u32]);
+    #[test]
+    fn ring_shrink_len_to_newest() {
+        let mut rb = RbShrink::new();
+        rb.extend(0..8);    // Wrapped, contains 3..8
+        let buffer = rb.buffer;
+        let newest = rb.newest().unwrap() as *const u32;
+
+        rb.shrink_len_to_newest(2);
+        assert!(rb.iter().eq([6, 7].iter()));
+        assert_eq!(rb.buffer, buffer);
+        assert!(core::ptr::eq(rb.newest().unwrap(), newest));
+
+        rb.shrink_len_to_newest(5);
+        assert_eq!(rb.len(), 2);
+        rb.shrink_len_to_newest(0);
+        assert!(rb.is_empty());
+        rb.push(8);
+        assert!(rb.iter().eq([8].iter()));
+
+        let mut rb = RbShrinkUnchecked::new();
+        rb.extend(0..300);
+        rb.shrink_len_to_newest(2);
+        assert!(rb.iter().eq([298, 299].iter()));
+    }
+
+    // Test last change skipping trailing duplicates
+    ring!(RbDistinct[usize;8]);
+    #[test]
+    fn ring_last_distinct() {
+        let mut rb = RbDistinct::new();
+        assert_eq!(rb.last_distinct(), None);
+
+        rb.extend([1, 1]);
+        assert_eq!(rb.last_distinct(), None);
+
+        rb.extend([2, 2, 3, 3, 3]);
+        assert_eq!(rb.last_distinct(), Some(&3));
+        assert_eq!(rb.iter().position(|item| core::ptr::eq(item, rb.last_distinct().unwrap())), Some(4));
+
+        rb.push(2);
+        assert_eq!(rb.last_distinct(), Some(&2));
+        assert!(core::ptr::eq(rb.last_distinct().unwrap(), rb.iter().next_back().unwrap()));
+    }
+
+    // Test resuming a parser from a checkpoint
+    ring!(RbCheckpoint[u8;8]);
+    #[test]
+    fn ring_as_slice_from() {
+        let mut rb = RbCheckpoint::new();
+        rb.extend(*b"GET /");
+        assert_eq!(rb.as_slice_from(0), (&b"GET /"[..], &b""[..]));
+        assert_eq!(rb.as_slice_from(4), (&b"/"[..], &b""[..]));
+        assert_eq!(rb.as_slice_from(5), (&b""[..], &b""[..]));
+        assert_eq!(rb.as_slice_from(9), (&b""[..], &b""[..]));
+
+        for _ in 0..4 {
+            rb.pop();
+        }
+        rb.extend(*b"a b\n");   // Wrap after "/a b"
+        assert_eq!(rb.physical_head(), 1);
+        assert_eq!(rb.as_slice_from(1), (&b"a b"[..], &b"\n"[..]));
+        assert_eq!(rb.as_slice_from(4), (&b"\n"[..], &b""[..]));
+        assert_eq!(rb.len(), 5);
+    }
+
+    // Test counting status codes
+    ring!(RbStatus[u8;8]);
+    ring!(RbDelta[i16;4]);
+    #[test]
+    fn ring_histogram() {
+        let mut rb = RbStatus::new();
+        assert_eq!(rb.histogram::<4>(), [0; 4]);
+
+        rb.extend([9, 0, 1, 3, 2, 1, 7, 1]);   // 9 is overwritten
+        assert_eq!(rb.histogram::<4>(), [1, 3, 1, 2]);
+        assert_eq!(rb.histogram::<8>(), [1, 3, 1, 1, 0, 0, 0, 1]);
+        assert_eq!(rb.histogram::<1>(), [7]);
+        assert_eq!(rb.histogram::<0>(), []);
+
+        // Negative values are out of range
+        let mut rb = RbDelta::new();
+        rb.extend([-1, 0, 5]);
+        assert_eq!(rb.histogram::<3>(), [1, 0, 2]);
+    }
+
+    // Test finding a delimiter
+    ring!(RbLines[u8;8]);
+    #[test]
+    fn ring_find_byte() {
+        let mut rb = RbLines::new();
+        rb.extend(*b"ok\nab");
+        assert_eq!(rb.find_byte(b'\n'), Some(2));
+
+        for _ in 0..3 {
+            rb.pop();
+        }
+        assert_eq!(rb.find_byte(b'\n'), None);
+
+        rb.extend(*b"cd\ne");   // Delimiter on the last slot before wrapping
+        assert_eq!(rb.physical_head(), 1);
+        assert_eq!(rb.find_byte(b'\n'), Some(4));
+
+        let mut line = [0; 5];
+        rb.items(&mut line);
+        assert_eq!(&line, b"abcd\n");
+    }
+
+    // Test finding a pattern across the end of the buffer
+    ring!(RbPattern[u8;8]);
+    #[test]
+    fn ring_contains_subsequence() {
+        let mut rb = RbPattern::new();
+        rb.extend(*b"xxxxxx");
+        for _ in 0..6 {
+            rb.pop();
+        }
+        rb.extend(*b"a\x7E\x7Eb");  // Sync word wraps at the end of the buffer
+        assert_eq!(rb.physical_tail(), 6);
+
+        assert!(rb.contains_subsequence(&[0x7E, 0x7E]));
+        assert!(rb.contains_subsequence(b"a\x7E\x7Eb"));
+        assert!(rb.contains_subsequence(&[]));
+        assert!(!rb.contains_subsequence(&[0x7E, 0x7E, 0x7E]));
+        assert!(!rb.contains_subsequence(b"ba"));
+        assert!(!rb.contains_subsequence(b"a\x7E\x7Ebcdef"));
+    }
+
+    // Test all or nothing drain into a block
+    ring!(RbBlocks[u8;8]);
+    #[test]
+    fn ring_drain_to_slice_exact() {
+        let mut rb = RbBlocks::new();
+        rb.extend(0..10);   // Wrapped, contains 3..10
+
+        let mut block = [0; 4];
+        assert!(rb.drain_to_slice_exact(&mut block));
+        assert_eq!(block, [3, 4, 5, 6]);
+        assert_eq!(rb.len(), 3);
+
+        assert!(!rb.drain_to_slice_exact(&mut block));
+        assert_eq!(block, [3, 4, 5, 6]);
+        assert_eq!(rb.len(), 3);
+
+        assert!(rb.drain_to_slice_exact(&mut block[..3]));
+        assert_eq!(block, [7, 8, 9, 6]);
+        assert!(rb.is_empty());
+    }
+
+    // Test transferring elements between buffers
+    ring!(RbStaging[u16;6]);
+    #[test]
+    fn ring_drain_into() {
+        let mut staging = RbStaging::new();
+        let mut transmit = RbStaging::new();
+        staging.extend(0..7);   // Wrapped, contains 2..7
+        transmit.extend([0, 1]);
+
+        // Partway once transmit is full
+        assert_eq!(staging.drain_into(&mut transmit), 3);
+        assert!(transmit.iter().eq([0, 1, 2, 3, 4].iter()));
+        assert!(staging.iter().eq([5, 6].iter()));
+        assert_eq!(staging.drain_into(&mut transmit), 0);
+
+        // Room for all
+        transmit.reset();
+        assert_eq!(staging.drain_into(&mut transmit), 2);
+        assert!(transmit.iter().eq([5, 6].iter()));
+        assert!(staging.is_empty());
+    }
+
+    // Test draining into scratch buffers
+    ring!(RbScratch[u16;8]);
+    #[test]
+    fn ring_collect_into() {
+        let mut rb = RbScratch::new();
+        rb.extend(0..10);   // Wrapped, contains 3..10
+
+        let mut small = [0; 4];
+        assert_eq!(rb.collect_into(&mut small), 4);
+        assert_eq!(small, [3, 4, 5, 6]);
+        assert_eq!(rb.len(), 3);
+
+        let mut large = [0; 8];
+        assert_eq!(rb.collect_into(&mut large), 3);
+        assert_eq!(large, [7, 8, 9, 0, 0, 0, 0, 0]);
+        assert!(rb.is_empty());
+        assert_eq!(rb.collect_into(&mut large), 0);
+    }
+
+    // Test peeking and popping a matching prefix
+    ring!(RbExpire[u32;8]);
+    #[test]
+    fn ring_peek_pop_while() {
+        let mut rb = RbExpire::new();
+        rb.extend([5, 8, 13, 9, 21, 34, 55, 2]);    // Wrapped, contains 8..=2
+        let now = 15;
+
+        // Stops at 21 even if 2 is lower
+        assert_eq!(rb.peek_while(|&deadline| deadline < now), 3);
+        assert_eq!(rb.len(), 7);
+        assert_eq!(rb.pop_while(|&deadline| deadline < now), 3);
+        assert!(rb.iter().eq([21, 34, 55, 2].iter()));
+
+        assert_eq!(rb.pop_while(|_| true), 4);
+        assert_eq!(rb.pop_while(|_| true), 0);
+    }
+
+    // Test searching from both ends
+    ring!(RbSearch[usize;6]);
+    #[test]
+    fn ring_position() {
+        let mut rb = RbSearch::new();
+        rb.extend(0..4);
+        while rb.pop().is_some() {}
+
+        // Wrap elements
+        rb.extend([1, 2, 3, 2, 1]);
+
+        assert_eq!(rb.position(&2), Some(1));
+        assert_eq!(rb.rposition(&2), Some(3));
+        assert_eq!(rb.position(&1), Some(0));
+        assert_eq!(rb.rposition(&1), Some(4));
+        assert_eq!(rb.rposition(&3), Some(2));
+        assert_eq!(rb.rposition(&5), None);
+    }
+
+    // Test folding elements in FIFO order
+    ring!(RbFold[u8;6]);
+    #[test]
+    fn ring_fold() {
+        let mut rb = RbFold::new();
+        assert_eq!(rb.fold(0u32, |acc, &item| acc + item as u32), 0);
+
+        rb.extend([9, 1, 2, 3, 4, 5]);  // Wrapped, contains 1..=5
+
+        let mut manual = 0u32;
+        for &item in rb.iter() {
+            manual = manual * 10 + item as u32;
+        }
+        assert_eq!(rb.fold(0u32, |acc, &item| acc * 10 + item as u32), manual);
+        assert_eq!(manual, 12345);
+    }
+
+    // Test selecting elements by key
+    #[derive(Clone, Copy, Default)]
+    struct Packet {
+        id : u32,
+        priority : u8,
+    }
+    ring!(RbPackets[Packet;5]);
+    #[test]
+    fn ring_min_max_by_key() {
+        let mut rb = RbPackets::new();
+        assert!(rb.max_by_key(|packet| packet.priority).is_none());
+
+        for (id, priority) in [(1, 9), (2, 3), (3, 7), (4, 1), (5, 7), (6, 3)] {
+            rb.push(Packet { id, priority });
+        }
+
+        // Packets 1 and 2 were overwritten, newest of equal maximum is returned
+        assert_eq!(rb.max_by_key(|packet| packet.priority).unwrap().id, 5);
+        assert_eq!(rb.min_by_key(|packet| packet.priority).unwrap().id, 4);
+    }
+
+    // Test binary search of sorted elements
+    ring!(RbSorted[u32;8]);
+    #[test]
+    fn ring_binary_search() {
+        let mut rb = RbSorted::new();
+        assert_eq!(rb.binary_search(&1), Err(0));
+
+        rb.extend([10, 20, 30, 40, 50, 60, 70, 80, 90]);    // Wrapped, contains 30..=90
+        assert_ne!(rb.physical_tail(), 0);
+
+        for (i, item) in rb.iter().enumerate() {
+            assert_eq!(rb.binary_search(item), Ok(i));
+        }
+        assert_eq!(rb.binary_search(&5), Err(0));
+        assert_eq!(rb.binary_search(&55), Err(3));
+        assert_eq!(rb.binary_search(&100), Err(7));
+    }
+
+    // Test checking the order of elements
+    ring!(RbOrdered[f32;5]);
+    #[test]
+    fn ring_is_sorted() {
+        let mut rb = RbOrdered::new();
+        assert!(rb.is_sorted());
+
+        rb.extend([1.0, 2.0, 2.0, 3.5]);
+        assert!(rb.is_sorted());
+        rb.push(3.0);
+        assert!(!rb.is_sorted());
+
+        // Sorted across the wrap
+        rb.extend([4.0, 5.0, 6.0]);
+        assert_ne!(rb.physical_tail(), 0);
+        assert!(rb.iter().eq([3.0, 4.0, 5.0, 6.0].iter()));
+        assert!(rb.is_sorted());
+
+        // NaN is not comparable
+        rb.push(f32::NAN);
+        assert!(!rb.is_sorted());
+    }
+
+    // Test raw pointer and physical indexes
+    ring!(RbRaw[u8;5]);
+    #[test]
+    fn ring_raw_parts() {
+        let mut rb = RbRaw::new();
+        assert_eq!(rb.as_ptr(), &rb.buffer[0] as *const u8);
+        assert_eq!(rb.as_mut_ptr() as *const u8, rb.as_ptr());
+
+        rb.extend([1, 2, 3, 4, 5, 6]);
+        rb.pop();
+        assert_eq!(rb.physical_head(), 1);
+        assert_eq!(rb.physical_tail(), 3);
+
+        // Oldest element is at physical tail
+        let oldest = unsafe { *rb.as_ptr().add(rb.physical_tail()) };
+        assert_eq!(oldest, 4);
+        assert_eq!(rb.len(), 3);
+    }
+
+    // Test read-only cursors
+    ring!(RbCursors[usize;5]);
+    #[test]
+    fn ring_head_tail() {
+        let mut rb = RbCursors::new();
+        assert_eq!((rb.head(), rb.tail()), (0, 0));
+
+        rb.extend(0..3);
+        rb.pop();
+        assert_eq!((rb.head(), rb.tail()), (3, 1));
+
+        rb.extend(3..6);    // Wrapped
+        assert_eq!((rb.head(), rb.tail()), (1, 2));
+        assert_eq!((rb.head(), rb.tail()), (rb.physical_head(), rb.physical_tail()));
+    }
+
+    // Test struct literal of the same module, only made of the 1.0.0 fields
+    ring!(RbLiteral[usize;4]);
+    #[test]
+    fn ring_struct_literal() {
+        let rb = RbLiteral { tail : 3, head : 1, buffer : [7, 0, 0, 5] };
+        assert!(rb.iter().eq([5, 7].iter()));
+
+        let RbLiteral { tail, head, .. } = rb;
+        assert_eq!((head, tail), (rb.head(), rb.tail()));
+    }
+
+    // Test conversion into array
+    ring!(RbArray[usize;6]);
+    #[test]
+    fn ring_into_array() {
+        let mut rb = RbArray::new();
+        rb.extend(0..9);
+        rb.pop();
+
+        let (array, len) = rb.into_array();
+        assert_eq!(len, 4);
+        assert_eq!(array[..len], [5, 6, 7, 8]);
+    }
+
+    // Test derived clone keeps the exact state
+    ring!(#[derive(Clone)] RbClone[usize;6]);
+    #[test]
+    fn ring_derive_clone() {
+        let mut rb = RbClone::new();
+        rb.extend(0..9);    // Wrapped, contains 4..9
+        rb.pop();
+
+        let mut copy = rb.clone();
+        assert_eq!((copy.head(), copy.tail()), (rb.head(), rb.tail()));
+        assert_eq!(copy.buffer, rb.buffer);
+        assert!(copy.iter().eq(rb.iter()));
+
+        copy.push(9);
+        assert_eq!(rb.len(), 4);
+    }
+
+    // Test slice-like index and iteration
+    ring!(RbView[usize;5]);
+    fn sum_ordered<'a, V : IntoIterator<Item = &'a usize>>(view : V) -> usize {
+        view.into_iter().enumerate().map(|(i, item)| i * item).sum()
+    }
+    #[test]
+    fn ring_index_view() {
+        let mut rb = RbView::new();
+        rb.extend(0..7);    // Wrapped, contains 3..7
+
+        for i in 0..rb.len() {
+            assert_eq!(rb[i], i + 3);
+        }
+        assert_eq!(sum_ordered(&rb), 4 + 10 + 18);
+
+        let mut count = 0;
+        for item in &rb {
+            assert_eq!(*item, rb[count]);
+            count += 1;
+        }
+        assert_eq!(count, 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn ring_index_out_of_range() {
+        let mut rb = RbView::new();
+        rb.extend(0..7);
+        let _ = rb[4];
+    }
+
+    // Test iterating from both ends
+    ring!(RbIter[usize;6]);
+    #[test]
+    fn ring_iter_rev() {
+        let mut rb = RbIter::new();
+        assert!(rb.iter().next().is_none());
+        assert!(rb.iter().next_back().is_none());
+
+        rb.extend(0..8);
+        assert!(rb.iter().eq([3, 4, 5, 6, 7].iter()));
+        assert!(rb.iter().rev().eq([7, 6, 5, 4, 3].iter()));
+
+        // Both ends meet across the wrap
+        let mut iter = rb.iter();
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next_back(), Some(&7));
+        assert_eq!(iter.next_back(), Some(&6));
+        assert_eq!(iter.next_back(), Some(&5));
+        assert_eq!(iter.next(), Some(&4));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    // Test exact size of consuming and borrowing iterators
+    ring!(RbIntoIter[usize;6]);
+    #[test]
+    fn ring_into_iter_size_hint() {
+        extern crate std;
+        use std::vec::Vec;
+
+        let mut rb = RbIntoIter::new();
+        rb.extend(0..8);    // Wrapped, contains 3..8
+
+        assert_eq!((&rb).into_iter().size_hint(), (5, Some(5)));
+
+        let iter = rb.into_iter();
+        assert_eq!(iter.size_hint(), (5, Some(5)));
+        let items : Vec<usize> = iter.collect();
+        assert_eq!(items, [3, 4, 5, 6, 7]);
+        assert_eq!(items.capacity(), 5);
+    }
+
+    // Test skipping elements of iterators
+    ring!(RbSkip[usize;8]);
+    #[test]
+    fn ring_iter_nth() {
+        let mut rb = RbSkip::new();
+        rb.extend(0..11);   // Wrapped, contains 4..11
+
+        let mut iter = rb.iter();
+        assert_eq!(iter.nth(2), Some(&6));
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.nth(1), Some(&8));
+        assert_eq!(iter.nth(5), None);
+        assert_eq!(iter.next(), None);
+
+        assert!(rb.iter_mut().skip(5).map(|item| *item).eq(9..11));
+
+        let mut drain = rb.drain();
+        assert_eq!(drain.nth(3), Some(7));
+        assert!(drain.eq(8..11));
+        assert!(rb.is_empty());
+    }
+
+    // Test exact size of iterators
+    #[test]
+    fn ring_iter_exact_size() {
+        let mut rb = RbIter::new();
+        rb.extend(0..8);
+
+        let mut iter = rb.iter();
+        assert_eq!(iter.len(), rb.len());
+        iter.next();
+        iter.next_back();
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+
+        let mut iter = rb.iter_mut();
+        assert_eq!(iter.len(), 5);
+        for item in iter.by_ref().take(4) {
+            *item *= 10;
+        }
+        assert_eq!(iter.len(), 1);
+        assert!(rb.iter().eq([30, 40, 50, 60, 7].iter()));
+
+        let mut drain = rb.drain();
+        assert_eq!(drain.len(), 5);
+        assert_eq!(drain.next(), Some(30));
+        assert_eq!(drain.next_back(), Some(7));
+        assert_eq!(drain.len(), 3);
+        assert!(drain.eq([40, 50, 60]));
+        assert!(rb.is_empty());
+    }
+
+    // Test contiguous view of the oldest elements
+    ring!(RbPeekSlice[u8;6]);
+    #[test]
+    fn ring_peek_slice() {
+        let mut rb = RbPeekSlice::new();
+        rb.extend([1, 2, 3, 4]);
+        assert_eq!(rb.peek_slice(3), Some(&[1, 2, 3][..]));
+        assert_eq!(rb.peek_slice(0), Some(&[][..]));
+        assert_eq!(rb.peek_slice(5), None);
+
+        rb.extend([5, 6, 7]);   // Wrapped, contains 3..=7 with 7 at index 0
+        assert_eq!(rb.peek_slice(3), Some(&[3, 4, 5][..]));
+        assert_eq!(rb.peek_slice(4), Some(&[3, 4, 5, 6][..]));
+        assert_eq!(rb.peek_slice(5), None);
+        assert_eq!(rb.len(), 5);
+    }
+
+    // Test peeking a fixed count of elements
+    ring!(RbPeekArray[u8;6]);
+    #[test]
+    fn ring_try_peek_array() {
+        let mut rb = RbPeekArray::new();
+        rb.extend([0xFF, 0xFF, 0xA5, 0x5A, 3]); // Header of 3 bytes
+        rb.pop();
+        rb.pop();
+        rb.extend([1, 2]);  // Wrapped, contains 0xA5..=2
+
+        assert_eq!(rb.try_peek_array::<3>(), Some([0xA5, 0x5A, 3]));
+        assert_eq!(rb.try_peek_array::<5>(), Some([0xA5, 0x5A, 3, 1, 2]));
+        assert_eq!(rb.try_peek_array::<6>(), None);
+        assert_eq!(rb.len(), 5);
+    }
+
+    // Test copying elements into an array
+    ring!(RbItems[usize;6]);
+    #[test]
+    fn ring_items() {
+        let mut rb = RbItems::new();
+        rb.extend(0..9);    // Wrapped, contains 4..9
+
+        let mut out = [0; 8];
+        assert_eq!(rb.items(&mut out), 5);
+        assert_eq!(out, [4, 5, 6, 7, 8, 0, 0, 0]);
+
+        let mut out = [0; 3];
+        assert_eq!(rb.items(&mut out), 3);
+        assert_eq!(out, [4, 5, 6]);
+        assert_eq!(rb.len(), 5);
+    }
+
+    // Test mapping into a ring buffer of another type
+    ring!(RbCounts[u8;6]);
+    ring!(RbVolts[f32;6]);
+    #[test]
+    fn ring_map_into() {
+        let mut counts = RbCounts::new();
+        counts.extend([0, 51, 102, 153, 204, 255, 0]);  // Wrapped, contains 102..=0
+
+        let volts : RbVolts = counts.map_into(|&count| count as f32 * 5.0 / 255.0);
+        assert_eq!(volts.len(), counts.len());
+        assert!(volts.iter().eq([2.0, 3.0, 4.0, 5.0, 0.0].iter()));
+    }
+
+    // Test expanding each element into runs
+    ring!(RbTokens[(u8, u8);4]);
+    ring!(RbDecoded[u8;8]);
+    #[test]
+    fn ring_flat_map_into() {
+        let mut tokens = RbTokens::new();
+        tokens.extend([(b'a', 2), (b'b', 0), (b'c', 3)]);
+
+        let decoded : RbDecoded = tokens.flat_map_into(|&(byte, count)| core::iter::repeat_n(byte, count as usize));
+        assert!(decoded.iter().eq(b"aaccc".iter()));
+
+        // Overflowing keeps the newest
+        tokens.push((b'd', 5));    // Overwrites (b'a', 2)
+        let decoded : RbDecoded = tokens.flat_map_into(|&(byte, count)| core::iter::repeat_n(byte, count as usize));
+        assert!(decoded.iter().eq(b"ccddddd".iter()));
+    }
+
+    // Test combining 2 buffers elementwise
+    ring!(RbChannel[i16;8]);
+    #[test]
+    fn ring_zip_with() {
+        let mut left = RbChannel::new();
+        let mut right = RbChannel::new();
+        left.extend([1, 2, 3, 4, 5, 6, 7, 8, 9]);   // Wrapped, contains 3..=9
+        right.extend([-10, 20, -30, 40]);
+
+        let mix = left.zip_with(&right, |l, r| l + r);
+        assert_eq!(mix.len(), 4);
+        assert!(mix.iter().eq([-7, 24, -25, 46].iter()));
+
+        // Order of buffers doesn't change the length
+        assert_eq!(right.zip_with(&left, |l, r| l + r).len(), 4);
+    }
+
+    // Test alternating elements of 2 buffers
+    ring!(RbStreams[usize;8]);
+    #[test]
+    fn ring_interleave() {
+        let mut odd = RbStreams::new();
+        let mut even = RbStreams::new();
+        odd.extend([1, 3, 5]);
+        even.extend([2, 4]);
+
+        assert!(odd.interleave(&even).iter().eq([1, 2, 3, 4, 5].iter()));
+        assert!(even.interleave(&odd).iter().eq([2, 1, 4, 3, 5].iter()));
+        assert!(odd.interleave(&RbStreams::new()).iter().eq(odd.iter()));
+
+        // Overflowing keeps the newest
+        even.extend([6, 8, 10]);
+        assert!(odd.interleave(&even).iter().eq([2, 3, 4, 5, 6, 8, 10].iter()));
+    }
+
+    // Test merging 2 sorted buffers
+    ring!(RbEvents[u32;8]);
+    #[test]
+    fn ring_merge_sorted() {
+        let mut left = RbEvents::new();
+        let mut right = RbEvents::new();
+        left.extend([1, 4, 4, 9]);
+        right.extend([2, 4, 7]);
+
+        let merged = left.merge_sorted(&right);
+        assert!(merged.iter().eq([1, 2, 4, 4, 4, 7, 9].iter()));
+        assert!(right.merge_sorted(&RbEvents::new()).iter().eq(right.iter()));
+
+        // Overflowing keeps the smallest
+        right.extend([8, 10]);
+        let merged = left.merge_sorted(&right);
+        assert_eq!(merged.len(), RbEvents::capacity());
+        assert!(merged.iter().eq([1, 2, 4, 4, 4, 7, 8].iter()));
+    }
+
+    // Test splitting a wrapped buffer
+    ring!(RbSplit[usize;6]);
+    #[test]
+    fn ring_split_at() {
+        let mut rb = RbSplit::new();
+        rb.extend(0..8);
+
+        let (a, b) = rb.split_at(0);
+        assert!(a.eq([].iter()));
+        assert!(b.eq([3, 4, 5, 6, 7].iter()));
+
+        let (a, b) = rb.split_at(3);
+        assert!(a.eq([3, 4, 5].iter()));
+        assert!(b.eq([6, 7].iter()));
+
+        let (a, b) = rb.split_at(rb.len());
+        assert!(a.eq([3, 4, 5, 6, 7].iter()));
+        assert!(b.eq([].iter()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn ring_split_at_out_of_range() {
+        let rb = RbSplit::new();
+        let _ = rb.split_at(1);
+    }
+
+    // Test hexadecimal display of wrapped bytes
+    ring!(RbHex[u8;5]);
+    #[test]
+    fn ring_hex() {
+        extern crate std;
+        use std::format;
+
+        let mut rb = RbHex::new();
+        assert_eq!(format!("{}", rb.hex()), "");
+
+        rb.extend([0x00, 0x01, 0x02, 0xDE]);
+        rb.pop();
+        rb.pop();
+        rb.pop();
+        rb.extend([0xAD, 0xBE, 0x0F]);
+
+        assert_eq!(format!("{}", rb.hex()), "DE AD BE 0F");
+    }
+
+    // Test mutable access from both ends
+    ring!(RbPatch[usize;5]);
+    #[test]
+    fn ring_nth_mut() {
+        let mut rb = RbPatch::new();
+        assert!(rb.nth_newest_mut(0).is_none());
+
+        rb.extend(0..6);    // Wrapped, contains 2..6
+        *rb.nth_newest_mut(0).unwrap() = 50;
+        *rb.nth_newest_mut(3).unwrap() += 20;
+        *rb.nth_oldest_mut(1).unwrap() += 30;
+        assert!(rb.nth_oldest_mut(4).is_none());
+        assert!(rb.nth_newest_mut(4).is_none());
+
+        assert!(rb.iter().eq([22, 33, 4, 50].iter()));
+    }
+
+    // Test writing in place
+    #[derive(Clone, Copy, Default)]
+    struct Frame {
+        id : usize,
+        data : [u8; 32],
+    }
+    ring!(RbInPlace[Frame;4]);
+    #[test]
+    fn ring_push_if_space() {
+        let mut rb = RbInPlace::new();
+
+        for id in 1..4 {
+            let frame = rb.push_if_space().unwrap();
+            frame.id = id;
+            frame.data[0] = id as u8;
+        }
+
+        assert!(rb.is_full());
+        assert!(rb.push_if_space().is_none());
+
+        for id in 1..4 {
+            let frame = rb.pop().unwrap();
+            assert_eq!(frame.id, id);
+            assert_eq!(frame.data[0], id as u8);
+        }
+        assert!(rb.is_empty());
+    }
+
+    // Test persisting the exact state
+    ring!(RbPersist[u8;6]);
+    #[test]
+    fn ring_as_from_bytes() {
+        let mut rb = RbPersist::new();
+        rb.extend(1..=8);   // Wrapped, contains 4..=8
+        rb.pop();
+
+        let bytes = rb.as_bytes();
+        let restored = RbPersist::from_bytes(&bytes).unwrap();
+        assert_eq!(restored.physical_tail(), rb.physical_tail());
+        assert_eq!(restored.physical_head(), rb.physical_head());
+        assert_eq!(restored.buffer, rb.buffer);
+        assert!(restored.iter().eq([5, 6, 7, 8].iter()));
+
+        let mut corrupted = bytes;
+        corrupted[4] = 6;   // Head out of the backing array
+        assert!(RbPersist::from_bytes(&corrupted).is_none());
+    }
+
+    // Test removing from both ends with remaining count
+    ring!(RbSplitEnds[usize;5]);
+    #[test]
+    fn ring_split_first_last() {
+        let mut rb = RbSplitEnds::new();
+        rb.extend(0..6);    // Wrapped, contains 2..6
+
+        assert_eq!(rb.split_first(), Some((&2, 3)));
+        assert_eq!(rb.split_last(), Some((&5, 2)));
+        assert!(rb.iter().eq([3, 4].iter()));
+
+        assert_eq!(rb.split_last(), Some((&4, 1)));
+        assert_eq!(rb.split_first(), Some((&3, 0)));
+        assert!(rb.split_first().is_none());
+        assert!(rb.split_last().is_none());
+
+        rb.push(7);
+        assert_eq!(*rb.pop().unwrap(), 7);
+    }
+
+    // Test snapshot into an existing buffer
+    ring!(RbSnapshot[usize;6]);
+    #[test]
+    fn ring_clone_from_ring() {
+        let mut src = RbSnapshot::new();
+        src.extend(0..9);   // Wrapped, contains 4..9
+
+        let mut dst = RbSnapshot::new();
+        dst.extend(100..103);
+        dst.pop();
+
+        dst.clone_from_ring(&src);
+        assert!(dst.iter().eq(src.iter()));
+        assert_eq!(dst.physical_tail(), 0);
+
+        // Both buffers stay independent
+        src.push(9);
+        dst.push(10);
+        assert_eq!(*src.pop().unwrap(), 5);
+        assert!(dst.iter().copied().eq([5, 6, 7, 8, 10]));
+    }
+
+    // Test comparator membership and dedup
+    #[derive(Clone, Copy, Default)]
+    struct Job {
+        id : u16,
+        attempt : u8,
+    }
+    ring!(RbJobs[Job;8]);
+    #[test]
+    fn ring_contains_dedup_by() {
+        let mut rb = RbJobs::new();
+        for (id, attempt) in [(0, 0), (0, 0), (0, 0), (1, 0), (1, 1), (2, 0), (2, 5), (2, 0), (3, 1)] {
+            rb.push(Job { id, attempt });
+        }   // Wrapped, the 2 oldest jobs were overwritten
+
+        let same_id = |a : &Job, b : &Job| a.id == b.id;
+        assert!(rb.contains_by(&Job { id : 2, attempt : 9 }, same_id));
+        assert!(!rb.contains_by(&Job { id : 4, attempt : 0 }, same_id));
+
+        rb.dedup_by(|a, b| a.id == b.id);
+        assert_eq!(rb.len(), 4);
+        assert!(rb.iter().map(|job| (job.id, job.attempt)).eq([(0, 0), (1, 0), (2, 0), (3, 1)]));
+
+        rb.push(Job { id : 4, attempt : 0 });
+        assert_eq!(rb.iter().next_back().unwrap().id, 4);
+    }
+
+    // Test splitting into 2 buffers
+    ring!(RbSplitOff[usize;6]);
+    #[test]
+    fn ring_split_off() {
+        let mut rb = RbSplitOff::new();
+        rb.extend(0..3);
+        for _ in 0..3 {
+            rb.pop();
+        }
+        rb.extend(1..=5);   // Wrapped, contains 1..=5
+
+        let other = rb.split_off(2);
+        assert!(rb.iter().eq([1, 2].iter()));
+        assert!(other.iter().eq([3, 4, 5].iter()));
+
+        rb.push(6);
+        assert!(rb.iter().eq([1, 2, 6].iter()));
+
+        assert!(rb.split_off(3).is_empty());
+        assert!(rb.split_off(0).iter().eq([1, 2, 6].iter()));
+        assert!(rb.is_empty());
+    }
+
+    // Test sizing a buffer from its capacity
+    ring!(RbSized[u8; crate::capacity_for_bytes(1024)]);
+    #[test]
+    fn ring_capacity_for_bytes() {
+        assert_eq!(RbSized::capacity(), 1024);
+
+        let mut rb = RbSized::new();
+        rb.extend((0..1024).map(|i| i as u8));
+        assert!(rb.is_full());
+        assert_eq!(rb.len(), 1024);
+        assert_eq!(rb.pop(), Some(&0));
+    }
+
+    // Test replacing an element
+    ring!(RbReplace[usize;6]);
+    #[test]
+    fn ring_replace() {
+        let mut rb = RbReplace::new();
+        rb.extend(0..8);    // Wrapped, contains 3..8
+
+        assert_eq!(rb.replace(2, 50), Some(5));
+        assert!(rb.iter().eq([3, 4, 50, 6, 7].iter()));
+        assert_eq!(rb.replace(2, 5), Some(50));
+        assert_eq!(rb.replace(5, 8), None);
+        assert!(rb.iter().eq([3, 4, 5, 6, 7].iter()));
+    }
+
+    // Test removing without preserving order
+    ring!(RbSwapRemove[usize;6]);
+    #[test]
+    fn ring_swap_remove() {
+        let mut rb = RbSwapRemove::new();
+        rb.extend(0..8);    // Wrapped, contains 3..8
+
+        assert_eq!(rb.swap_remove(1), Some(4));
+        assert_eq!(rb.len(), 4);
+        assert!(rb.iter().eq([3, 7, 5, 6].iter()));
+
+        // Removing the newest element doesn't move any element
+        assert_eq!(rb.swap_remove(3), Some(6));
+        assert!(rb.iter().eq([3, 7, 5].iter()));
+        assert_eq!(rb.swap_remove(3), None);
+
+        rb.push(8);
+        assert!(rb.iter().eq([3, 7, 5, 8].iter()));
+    }
+
+    // Test removing while preserving order
+    ring!(RbRemove[usize;7]);
+    #[test]
+    fn ring_remove() {
+        let mut rb = RbRemove::new();
+        rb.extend(0..4);
+        for _ in 0..4 {
+            rb.pop();
+        }
+        rb.extend(1..6);    // Elements wrap at the end of the buffer
+
+        assert_eq!(rb.remove(2), Some(3));
+        assert!(rb.iter().eq([1, 2, 4, 5].iter()));
+
+        assert_eq!(rb.remove(0), Some(1));
+        assert_eq!(rb.remove(2), Some(5));
+        assert_eq!(rb.remove(2), None);
+        assert!(rb.iter().eq([2, 4].iter()));
+
+        rb.push(6);
+        assert!(rb.iter().eq([2, 4, 6].iter()));
+    }
+
+    // Test inserting while preserving order
+    ring!(RbInsert[usize;7]);
+    #[test]
+    fn ring_insert() {
+        let mut rb = RbInsert::new();
+        rb.extend(0..4);
+        for _ in 0..4 {
+            rb.pop();
+        }
+        rb.extend([2, 4]);
+
+        assert_eq!(rb.insert(0, 1), Ok(()));
+        assert_eq!(rb.insert(2, 3), Ok(()));
+        assert_eq!(rb.insert(4, 5), Ok(()));
+        assert!(rb.iter().eq([1, 2, 3, 4, 5].iter()));
+
+        assert_eq!(rb.insert(2, 9), Ok(()));
+        assert!(rb.is_full());
+        assert_eq!(rb.insert(0, 10), Err(10));
+        assert!(rb.iter().eq([1, 2, 9, 3, 4, 5].iter()));
+    }
+
+    // Test reset to new state
+    ring!(RbReset[usize;8]);
+    #[test]
+    fn ring_reset() {
+        let mut rb = RbReset::new();
+        rb.extend(1..12);
+        rb.pop();
+
+        rb.reset();
+        assert_eq!(rb.len(), 0);
+        assert!(rb.pop().is_none());
+        assert!(rb.buffer.iter().all(|&item| item == 0));
+
+        rb.push(5);
+        assert_eq!(*rb.pop().unwrap(), 5);
+    }
+
+    // Test shrinking keeps capacity and elements
+    ring!(RbShrinkFit[usize;8]);
+    #[test]
+    fn ring_shrink_to_fit() {
+        let mut rb = RbShrinkFit::new();
+        rb.extend(1..4);
+
+        rb.shrink_to_fit();
+        assert_eq!(RbShrinkFit::capacity(), 7);
+        assert!(rb.iter().eq([1, 2, 3].iter()));
+    }
+
+    // Test reporting overwrites
+    ring!(RbReport[usize;4]);
+    #[test]
+    fn ring_push_reporting() {
+        let mut rb = RbReport::new();
+
+        for i in 0..3 {
+            assert!(!rb.push_reporting(i));
+        }
+        for i in 3..6 {
+            assert!(rb.push_reporting(i));
+        }
+
+        rb.pop();
+        assert!(!rb.push_reporting(6));
+        assert!(rb.iter().eq([4, 5, 6].iter()));
+    }
+
+    // Test shift register delaying a stream
+    ring!(RbDelay[i32;4]);
+    #[test]
+    fn ring_shift_in() {
+        let mut rb = RbDelay::new();
+
+        for sample in 0..3 {
+            assert_eq!(rb.shift_in(sample), None);
+        }
+
+        // Stream comes out delayed by capacity samples
+        for sample in 3..20 {
+            assert_eq!(rb.shift_in(sample), Some(sample - 3));
+        }
+        assert!(rb.iter().eq([17, 18, 19].iter()));
+    }
+
+    // Test accessors of both ends
+    ring!(RbEnds[u8;4]);
+    #[test]
+    fn ring_front_back() {
+        let mut rb = RbEnds::new();
+        assert_eq!((rb.oldest(), rb.newest(), rb.front(), rb.back()), (None, None, None, None));
+        assert_eq!((rb.pop_front(), rb.pop_back()), (None, None));
 
-}
+        rb.extend([1, 2, 3, 4, 5]);    // Wrapped, contains 3..=5
+        assert_eq!(rb.oldest(), Some(&3));
+        assert_eq!(rb.front(), rb.oldest());
+        assert_eq!(rb.newest(), Some(&5));
+        assert_eq!(rb.back(), rb.newest());
 
-#[cfg(test)]
-#[cfg(not(feature = "no_limit"))]   // Only limit features are tested
-pub(crate) mod tests_checked {
+        // Front is popped first, back was pushed last
+        assert_eq!(rb.pop_front(), Some(3));
+        assert_eq!(rb.pop_back(), Some(5));
+        assert!(rb.iter().eq([4].iter()));
+        assert_eq!(rb.front(), rb.back());
+        assert_eq!(rb.pop_back(), Some(4));
+        assert!(rb.is_empty());
+    }
 
-    // Test the lower limit of ring buffer
-    ring!(TooSmall[usize;super::super::NSRB_LOWER_LIMIT - 1]);
+    // Test recording changes only
+    ring!(RbChanges[u8;8]);
     #[test]
-    #[should_panic]
-    fn ring_lower_limit() {
-        let _ = TooSmall::new();
+    fn ring_push_if_changed() {
+        let mut rb = RbChanges::new();
+        let pushed : [bool; 5] = core::array::from_fn(|i| rb.push_if_changed([1, 1, 2, 2, 3][i]));
+
+        assert_eq!(pushed, [true, false, true, false, true]);
+        assert!(rb.iter().eq([1, 2, 3].iter()));
+
+        // Compared to the newest element only
+        assert!(rb.push_if_changed(1));
+
+        // Always pushes into an empty buffer
+        rb.retain_newest(0);
+        assert!(rb.push_if_changed(1));
+        assert_eq!(rb.len(), 1);
     }
 
-    // Test the upper limit of ring buffer
-    ring!(TooBig[usize;super::super::NSRB_UPPER_LIMIT + 1]);
+    // Test FIR filter over delay line taps
+    ring!(RbTaps[i32;4]);
     #[test]
-    #[should_panic]
-    fn ring_upper_limit() {
-        let _ = TooBig::new();
+    fn ring_tap() {
+        const COEFFICIENTS : [i32; 3] = [4, 2, 1];
+        let mut rb = RbTaps::new();
+        assert!(rb.tap(0).is_none());
+
+        let mut output = [0; 6];
+        for (sample, out) in [1, 0, 0, 10, 20, 30].into_iter().zip(output.iter_mut()) {
+            rb.push(sample);
+            *out = COEFFICIENTS.iter().enumerate()
+                .map(|(n, coefficient)| rb.tap(n).map_or(0, |&x| x * coefficient))
+                .sum();
+        }
+
+        // Impulse response then filtered ramp
+        assert_eq!(output, [4, 2, 1, 40, 100, 170]);
+        assert_eq!(rb.tap(2), Some(&10));
+        assert!(rb.tap(3).is_none());
     }
 
-    // Test push and pop of ring buffer
-    ring!(RbPP[usize;10]);
+    // Test sliding blocks into the window
+    ring!(RbColumns[u8;5]);
     #[test]
-    fn ring_push_pop() {
-        let mut rb = RbPP::new();
+    fn ring_slide() {
+        let mut rb = RbColumns::new();
+        rb.extend([1, 2, 3]);
 
-        for i in 0..15 {
-            rb.push(i);
+        // Partial slide keeps some old elements
+        rb.slide(&[4, 5]);
+        assert!(rb.iter().eq([2, 3, 4, 5].iter()));
+        rb.slide(&[]);
+        assert!(rb.iter().eq([2, 3, 4, 5].iter()));
+
+        // Full replacement keeps the newest items
+        rb.slide(&[6, 7, 8, 9, 10, 11]);
+        assert!(rb.iter().eq([8, 9, 10, 11].iter()));
+        assert!(rb.is_full());
+        rb.slide(&[12, 13, 14, 15]);
+        assert!(rb.iter().eq([12, 13, 14, 15].iter()));
+
+        // Cursors are coherent after a full replacement
+        rb.push(16);
+        assert_eq!(rb.pop(), Some(&13));
+        assert!(rb.iter().eq([14, 15, 16].iter()));
+        #[cfg(debug_assertions)]
+        rb.assert_invariants();
+    }
+
+    // Test constructing elements in place
+    ring!(RbEmplace[usize;4]);
+    #[test]
+    fn ring_emplace() {
+        let mut rb = RbEmplace::new();
+        let mut calls = 0;
+
+        for i in 0..3 {
+            assert!(rb.try_emplace(|| { calls += 1; i }));
         }
+        assert!(rb.is_full());
+        assert!(!rb.try_emplace(|| { calls += 1; 3 }));
+        assert_eq!(calls, 3);
 
-        for i in 6..15 {
+        // emplace always push
+        rb.emplace(|| { calls += 1; 3 });
+        assert_eq!(calls, 4);
+
+        for i in 1..4 {
             assert_eq!(*rb.pop().unwrap(), i);
         }
+    }
 
-        assert!(rb.pop().is_none());
+    // Test recycling popped elements into a pool
+    ring!(RbRecycle[usize;4]);
+    #[test]
+    fn ring_pop_with() {
+        let mut rb = RbRecycle::new();
+        let mut pool = RbRecycle::new();
+        rb.extend([7, 8]);
+
+        while rb.pop_with(|handle| pool.push(handle)) {}
+        assert!(!rb.pop_with(|_| unreachable!()));
+        assert!(pool.iter().eq([7, 8].iter()));
+
+        // Reuse from the pool rather than making new handles
+        rb.push(pool.pop_front().unwrap());
+        assert_eq!(rb.pop_front(), Some(7));
+    }
+
+    // Test waiting for room or elements with a backoff making progress
+    ring!(RbSpin[usize;3]);
+    #[test]
+    fn ring_push_pop_spin() {
+        let mut rb = RbSpin::new();
+        let mut drained = [0; 8];
+        let mut count = 0;
+
+        for i in 0..8 {
+            rb.push_spin(i, |rb| {
+                drained[count] = rb.pop_front().unwrap();
+                count += 1;
+            });
+        }
+        assert_eq!(count, 6);
+        assert_eq!(drained[..6], [0, 1, 2, 3, 4, 5]);
+
+        let mut next = 8;
+        for i in 6..12 {
+            assert_eq!(rb.pop_spin(|rb| { rb.push(next); next += 1; }), i);
+        }
+        assert!(rb.is_empty());
+    }
+
+    // Test filling with a generator
+    ring!(RbFill[usize;6]);
+    #[test]
+    fn ring_fill_from_fn() {
+        let mut rb = RbFill::new();
+        rb.push(0);
+
+        let mut counter = 0;
+        rb.fill_from_fn(|| { counter += 1; counter * 10 });
+        assert!(rb.is_full());
+        assert!(rb.iter().eq([0, 10, 20, 30, 40].iter()));
+
+        // No-op once full
+        rb.fill_from_fn(|| { counter += 1; 0 });
+        assert_eq!(counter, 4);
+    }
+
+    // Test arbitrary buffers respect invariants
+    #[cfg(feature = "arbitrary")]
+    ring!(RbArbitrary[u16;16]);
+    #[test]
+    #[cfg(feature = "arbitrary")]
+    fn ring_arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let data : [u8; 256] = core::array::from_fn(|i| (i * 37 % 251) as u8);
+        let mut u = Unstructured::new(&data);
+
+        while !u.is_empty() {
+            let rb = RbArbitrary::arbitrary(&mut u).unwrap();
+            assert!(rb.len() <= RbArbitrary::capacity());
+            assert_eq!(rb.iter().count(), rb.len());
+            assert!(rb.physical_head() < RbArbitrary::SIZE);
+            assert!(rb.physical_tail() < RbArbitrary::SIZE);
+        }
+    }
+
+    // Test zeroizing the backing array
+    #[cfg(feature = "zeroize")]
+    ring!(RbSecret[u8;8]);
+    #[test]
+    #[cfg(feature = "zeroize")]
+    fn ring_zeroize() {
+        use zeroize::{Zeroize, Zeroizing};
+
+        let mut rb = RbSecret::new();
+        rb.extend(*b"password");    // Wrapped, every slot written
+        rb.pop();
+        rb.zeroize();
+        assert_eq!(rb.buffer, [0; 8]);
+        assert!(rb.is_empty());
+        assert_eq!(rb.physical_head(), 0);
+
+        rb.extend(*b"key");
+        let mut secret = Zeroizing::new(rb);
+        assert_eq!(secret.pop(), Some(&b'k'));
     }
 
     // Test extra clear and len implementation
@@ -364,17 +3855,383 @@ pub(crate) mod tests_checked {
 
 
 #[cfg(test)]
-#[cfg(not(feature = "no_limit"))]   // Only limit features are tested
+pub(crate) mod tests_unlimited {
+    use crate::RingLen;
+
+    // Test buffers out of limits only when unlimited
+    ring!(@unlimited Unlimited[u8;super::super::NSRB_UPPER_LIMIT + 1]);
+    ring!(@unlimited UnlimitedSmall[u8;1]);
+    #[cfg(not(feature = "no_limit"))]
+    ring!(Limited[u8;super::super::NSRB_UPPER_LIMIT + 1]);
+    #[test]
+    fn unlimited_try_new() {
+        extern crate std;
+
+        let mut rb = std::boxed::Box::new(Unlimited::new());
+        rb.extend(0..=255);
+        assert_eq!(rb.len(), 256);
+
+        let mut small = UnlimitedSmall::new();
+        small.push(1);
+        assert!(small.is_empty());
+
+        #[cfg(not(feature = "no_limit"))]
+        assert_eq!(Limited::try_new().err(), Some(crate::RingError::TooLarge));
+    }
+
+    // Test fill ratio without capacity
+    #[test]
+    fn unlimited_occupancy() {
+        let mut small = UnlimitedSmall::new();
+        small.push(1);
+        assert_eq!(small.occupancy_permille(), 0);
+        #[cfg(feature = "float")]
+        assert_eq!(small.occupancy(), 0.0);
+    }
+
+    // Test sliding into a buffer without capacity
+    #[test]
+    fn unlimited_slide() {
+        let mut small = UnlimitedSmall::new();
+        small.slide(&[1, 2, 3]);
+        assert!(small.is_empty());
+        assert_eq!(small.iter().count(), 0);
+        assert_eq!(small.pop_front(), None);
+    }
+
+    // Test creating arbitrary states from raw parts
+    ring!(@unlimited UnlimitedRaw[u8;3]);
+    #[test]
+    fn unlimited_from_raw_parts() {
+        let rb = unsafe { UnlimitedRaw::from_raw_parts([1, 2, 3], 1, 2) };
+        assert!(rb.is_full());
+        assert!(rb.iter().eq([3, 1].iter()));
+    }
+
+}
+
+
+#[cfg(test)]
+pub(crate) mod tests_broadcast {
+    use crate::{Lagged, RingLen};
+
+    // Test readers limit
+    ring!(@broadcast(2) RbReaders[usize;10]);
+    #[test]
+    fn broadcast_subscribe() {
+        let mut rb = RbReaders::new();
+
+        assert_eq!(rb.subscribe(), Some(0));
+        assert_eq!(rb.subscribe(), Some(1));
+        assert_eq!(rb.subscribe(), None);
+
+        rb.unsubscribe(0);
+        assert_eq!(rb.subscribe(), Some(0));
+    }
+
+    // Test popping elements only affects readers that didn't receive them
+    ring!(@broadcast(2) RbRemoved[usize;10]);
+    #[test]
+    fn broadcast_remove() {
+        let mut rb = RbRemoved::new();
+        let fast = rb.subscribe().unwrap();
+        let slow = rb.subscribe().unwrap();
+
+        rb.extend([1, 2, 3, 4]);
+        for i in 1..=4 {
+            assert_eq!(rb.receive(fast), Ok(Some(&i)));
+        }
+
+        assert_eq!(rb.pop_front(), Some(1));
+        assert_eq!(rb.split_first(), Some((&2, 2)));
+        assert_eq!(rb.receive(fast), Ok(None));
+        assert_eq!(rb.receive(slow).unwrap_err().missed, 2);
+        assert_eq!(rb.receive(slow), Ok(Some(&3)));
+
+        rb.push(9);
+        assert_eq!(rb.receive(fast), Ok(Some(&9)));
+        assert_eq!(rb.receive(fast), Ok(None));
+        assert_eq!(rb.receive(slow), Ok(Some(&4)));
+        assert_eq!(rb.receive(slow), Ok(Some(&9)));
+    }
+
+    // Test reading with cursors without popping
+    ring!(@broadcast(1) RbCursor[usize;6]);
+    #[test]
+    fn broadcast_cursor_resume() {
+        let mut rb = RbCursor::new();
+        rb.extend([1, 2, 3]);
+
+        let (a, b, cursor) = rb.read_from(rb.cursor()).unwrap();
+        assert_eq!(a, &[1, 2, 3]);
+        assert!(b.is_empty());
+
+        rb.extend([4, 5, 6, 7]);
+        let (a, b, cursor) = rb.read_from(cursor).unwrap();
+        assert_eq!(a, &[4, 5, 6]);
+        assert_eq!(b, &[7]);
+
+        let (a, b, _) = rb.read_from(cursor).unwrap();
+        assert!(a.is_empty() && b.is_empty());
+
+        // Reading doesn't pop
+        assert_eq!(rb.len(), 5);
+        assert_eq!(*rb.pop().unwrap(), 3);
+    }
+
+    #[test]
+    fn broadcast_cursor_lagged() {
+        let mut rb = RbCursor::new();
+        let cursor = rb.cursor();
+
+        rb.extend(0..8);
+        let lagged = rb.read_from(cursor).unwrap_err();
+        assert_eq!(lagged.missed, 3);
+
+        let (a, b, _) = rb.read_from(lagged.cursor).unwrap();
+        assert_eq!(a, &[3, 4, 5]);
+        assert_eq!(b, &[6, 7]);
+
+        // Popped elements are lost for readers too
+        let cursor = rb.cursor();
+        rb.pop();
+        assert_eq!(rb.read_from(cursor).unwrap_err().missed, 1);
+    }
+
+    // Test cursors after removing elements
+    ring!(@broadcast(1) RbCursorRemove[usize;8]);
+    #[test]
+    fn broadcast_read_from_after_remove() {
+        let mut rb = RbCursorRemove::new();
+        rb.extend([1, 2, 3, 4, 5]);
+        let (_, _, cursor) = rb.read_from(rb.cursor()).unwrap();
+
+        assert_eq!(rb.split_first(), Some((&1, 4)));
+        rb.shrink_len_to_newest(2);
+        assert_eq!(rb.read_from(cursor), Ok((&[][..], &[][..], cursor)));
+
+        rb.push(9);
+        let (a, b, cursor) = rb.read_from(cursor).unwrap();
+        assert_eq!((a, b), (&[9][..], &[][..]));
+        assert_eq!(rb.read_from(cursor).unwrap().0, &[]);
+
+        // A cursor on the oldest element lags once it's removed
+        let oldest = rb.cursor();
+        rb.shrink_len_to_newest(1);
+        let lagged = rb.read_from(oldest).unwrap_err();
+        assert_eq!(lagged.missed, 2);
+        assert_eq!(rb.read_from(lagged.cursor).unwrap().0, &[9]);
+    }
+
+    // Test readers at different speeds
+    ring!(@broadcast(2) RbSpeed[usize;10]);
+    #[test]
+    fn broadcast_lag() {
+        let mut rb = RbSpeed::new();
+        let fast = rb.subscribe().unwrap();
+        let slow = rb.subscribe().unwrap();
+
+        for i in 0..20 {
+            rb.push(i);
+            assert_eq!(*rb.receive(fast).unwrap().unwrap(), i);
+        }
+        assert_eq!(rb.receive(fast), Ok(None));
+
+        match rb.receive(slow) {
+            Err(Lagged { missed, .. }) => assert_eq!(missed, 11),
+            _ => panic!("Slow reader should have lagged!"),
+        }
+
+        for i in 11..20 {
+            assert_eq!(*rb.receive(slow).unwrap().unwrap(), i);
+        }
+        assert_eq!(rb.receive(slow), Ok(None));
+    }
+
+}
+
+
+#[cfg(test)]
+pub(crate) mod tests_volatile {
+
+    // Test volatile push and pop
+    ring!(@volatile RbVolatile[u16;8]);
+    #[test]
+    fn volatile_push_pop() {
+        let mut rb = RbVolatile::new();
+
+        for i in 0..20 {
+            rb.push(i);
+        }
+
+        for i in 13..20 {
+            assert_eq!(rb.pop(), Some(i));
+        }
+        assert_eq!(rb.pop(), None);
+    }
+
+}
+
+
+#[cfg(test)]
+pub(crate) mod tests_align {
+
+    // Test backing array alignment
+    ring!(@align(64) RbAlign64[u8;100]);
+    ring!(@align(4096) RbAlign4096[u16;10]);
+    #[test]
+    fn align_as_ptr() {
+        let rb = RbAlign64::new();
+        assert_eq!(rb.as_ptr() as usize % 64, 0);
+        assert_eq!(core::mem::align_of::<RbAlign64>(), 64);
+
+        // Also on the stack next to a smaller value
+        let (_byte, mut rb) = (1u8, RbAlign4096::new());
+        assert_eq!(rb.as_ptr() as usize % 4096, 0);
+
+        rb.extend(0..12);
+        assert!(rb.iter().copied().eq(3..12));
+    }
+
+}
+
+
+#[cfg(test)]
+pub(crate) mod tests_scrub {
+
+    // Test popped slots are scrubbed
+    ring!(@scrub RbScrub[u8;4]);
+    #[test]
+    fn scrub_pop() {
+        let mut rb = RbScrub::new();
+        rb.extend([0xAA, 0xBB, 0xCC, 0xDD, 0xEE]);  // Wrapped, contains 0xCC..=0xEE
+
+        let slot = rb.physical_tail();
+        assert_eq!(rb.pop(), Some(0xCC));
+        assert_eq!(rb.buffer[slot], 0);
+
+        assert_eq!(rb.pop(), Some(0xDD));
+        assert_eq!(rb.pop(), Some(0xEE));
+        assert_eq!(rb.pop(), None);
+        assert!(rb.buffer.iter().all(|&item| item == 0));
+    }
+
+}
+
+
+#[cfg(test)]
+pub(crate) mod tests_movsum {
+    use crate::RingLen;
+
+    // Test incremental sum against brute force
+    ring!(@movsum RbSum[i64;8]);
+    #[test]
+    fn movsum_window_sum() {
+        let mut rb = RbSum::new();
+        assert_eq!(rb.window_sum(), 0);
+
+        for i in 0..100i64 {
+            rb.push(i * 7 - 300);
+            if i % 3 == 0 {
+                rb.pop();
+            }
+
+            let (a, b) = rb.slices(0, rb.len());
+            let sum : i64 = a.iter().chain(b.iter()).sum();
+            assert_eq!(rb.window_sum(), sum);
+        }
+
+        while rb.pop().is_some() {}
+        assert_eq!(rb.window_sum(), 0);
+    }
+
+    // Test priming the window with a baseline
+    ring!(@movsum RbPrime[i32;5]);
+    #[test]
+    fn movsum_clear_and_fill() {
+        let mut rb = RbPrime::new();
+        rb.extend([7, -3, 12]);
+
+        rb.clear_and_fill(25);
+        assert!(rb.is_full());
+        assert!(rb.iter().all(|&item| item == 25));
+        assert_eq!(rb.window_sum(), 100);
+
+        rb.push(5);
+        assert_eq!(rb.window_sum(), 80);
+    }
+
+}
+
+
+#[cfg(test)]
+pub(crate) mod tests_rollhash {
+
+    // Test incremental hash against recomputed hash
+    ring!(@rollhash RbHash[u8;8]);
+    #[test]
+    fn rollhash_rolling_hash() {
+        let mut rb = RbHash::new();
+        assert_eq!(rb.rolling_hash(), RbHash::hash_of(&[]));
+
+        for i in 0..200u32 {
+            rb.push((i * 37 % 256) as u8);
+            if i % 5 == 0 {
+                rb.pop();
+            }
+
+            let mut window = [0u8; 8];
+            let count = rb.items(&mut window);
+            assert_eq!(rb.rolling_hash(), RbHash::hash_of(&window[..count]));
+        }
+
+        while rb.pop().is_some() {}
+        assert_eq!(rb.rolling_hash(), 0);
+    }
+
+}
+
+
+#[cfg(test)]
+#[allow(clippy::unnecessary_cast)]   // Extra len() implementation casts like the 1.0.0 example
 pub(crate) mod tests_unchecked {
+    use crate::RingLen;
 
     // Test the upper limit of ring buffer
+    #[cfg(not(feature = "no_limit"))]
     ring!(@unchecked(u32) TooBig[usize]);
     #[test]
+    #[cfg(not(feature = "no_limit"))]
     #[should_panic]
     fn ring_upper_limit() {
         let _ = TooBig::new();
     }
 
+    // Test limits without panicking
+    #[test]
+    fn ring_try_new() {
+        #[cfg(not(feature = "no_limit"))]
+        assert_eq!(TooBig::try_new().err(), Some(crate::RingError::TooLarge));
+        assert!(RbPP::try_new().is_ok());
+    }
+
+    // Test converting between checked and unchecked of the same capacity
+    ring!(RbChecked8[u16;256]);
+    ring!(@unchecked(u8) RbUnchecked8[u16]);
+    #[test]
+    fn ring_into_ring() {
+        let mut checked = RbChecked8::new();
+        checked.extend(0..300);     // Wrapped, contains 45..300
+        assert_eq!(RbChecked8::capacity(), RbUnchecked8::capacity());
+
+        let unchecked : RbUnchecked8 = checked.into_ring();
+        assert_eq!(unchecked.len(), 255);
+        assert!(unchecked.iter().copied().eq(45..300));
+
+        let back : RbChecked8 = unchecked.into_ring();
+        assert!(back.iter().copied().eq(45..300));
+    }
+
     // Test push and pop of ring buffer
     ring!(@unchecked(u8) RbPP[usize]);
     #[test]
@@ -392,6 +4249,103 @@ pub(crate) mod tests_unchecked {
         assert!(rb.pop().is_none());
     }
 
+    // Test capacity in const context
+    const RB_CAP_ARRAY : [usize; RbPP::capacity()] = [0; RbPP::capacity()];
+    #[test]
+    fn ring_capacity() {
+        assert_eq!(RB_CAP_ARRAY.len(), u8::MAX as usize);
+        assert_eq!(RbPP::SIZE, u8::MAX as usize + 1);
+    }
+
+    // Test FIFO order after a full lap
+    ring!(@unchecked(u8) RbLap[usize]);
+    #[test]
+    fn ring_full_lap() {
+        let mut rb = RbLap::new();
+
+        for i in 0..u8::MAX as usize + 1 {
+            rb.push(i);
+        }
+        assert!(rb.is_full());
+
+        for i in 1..u8::MAX as usize + 1 {
+            assert_eq!(*rb.pop().unwrap(), i);
+        }
+        assert!(rb.pop().is_none());
+        assert!(rb.is_empty());
+    }
+
+    // Test fast and checked push give the same result
+    ring!(@unchecked(u8) RbPushFast[usize]);
+    ring!(@unchecked(u8) RbPushChecked[usize]);
+    #[test]
+    fn ring_push_checked() {
+        let mut fast = RbPushFast::new();
+        let mut checked = RbPushChecked::new();
+
+        for i in 0..700 {
+            fast.push(i);
+            checked.push_checked(i);
+            if i % 3 == 0 {
+                assert_eq!(fast.pop(), checked.pop());
+            }
+            assert_eq!(fast.physical_head(), checked.physical_head());
+        }
+        assert!(fast.iter().eq(checked.iter()));
+
+        // Paths can be mixed on the same buffer
+        for i in 0..300 {
+            if i % 2 == 0 { fast.push(i) } else { fast.push_checked(i) }
+        }
+        assert!(fast.iter().copied().eq(45..300));
+    }
+
+    // Test len and is_empty when head wrapped past tail
+    ring!(@unchecked(u8) RbLen[usize]);
+    #[test]
+    fn ring_len_wrap() {
+        let mut rb = RbLen::new();
+        assert!(rb.is_empty());
+
+        for i in 0..1000 {
+            assert_eq!(rb.len(), i.min(RbLen::capacity()));
+            rb.push(i);
+            assert!(!rb.is_empty());
+        }
+        assert!(rb.is_full());
+
+        for i in 0..200 {
+            rb.pop();
+            assert_eq!(rb.len(), RbLen::capacity() - i - 1);
+        }
+
+        // Wrap head numerically below tail
+        for i in 0..100 {
+            rb.push(i);
+        }
+        assert!(rb.head < rb.tail);
+        assert_eq!(rb.len(), 155);
+
+        while rb.pop().is_some() {}
+        assert_eq!(rb.len(), 0);
+        assert!(rb.is_empty());
+    }
+
+    // Test len with the u16 full range
+    ring!(@unchecked(u16) RbLenU16[u8]);
+    #[test]
+    fn ring_len_wrap_u16() {
+        let mut rb = RbLenU16::new();
+
+        for i in 0..200_000usize {
+            rb.push(i as u8);
+        }
+        assert_eq!(rb.len(), u16::MAX as usize);
+
+        rb.pop();
+        assert_eq!(rb.len(), u16::MAX as usize - 1);
+    }
+
     // Test extra clear and len implementation
     ring!(@unchecked(u8) RbExtra[usize]);
 
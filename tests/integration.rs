@@ -20,4 +20,19 @@ extern crate nsrb;
  fn ring_lower_limit() {
     let mut log = LogRB::new();
     log.push(LogEntry { time_date: SystemTime::now(), entry: [Default::default();256] });
- }
\ No newline at end of file
+ }
+
+ #[test]
+ fn ring_eq_slice_by() {
+    let entry = |byte : u8| LogEntry { time_date: SystemTime::now(), entry: [byte;256] };
+
+    let mut log = LogRB::new();
+    for byte in 0..12 {
+        log.push(entry(byte));
+    }
+
+    // Expected entries are stamped after the pushed ones.
+    let expected : Vec<LogEntry> = (3..12).map(entry).collect();
+    assert!(log.eq_slice_by(&expected, |a, b| a.entry == b.entry));
+    assert!(!log.eq_slice_by(&expected[1..], |a, b| a.entry == b.entry));
+ }